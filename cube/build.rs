@@ -1,11 +1,19 @@
 use std::env;
 
 fn main() {
-    if env::consts::OS == "macos" {
-        let dynamic_library_path = "/Applications/Ultraleap Hand Tracking.app/Contents/LeapSDK/lib";
-        println!("cargo:rustc-link-arg=-Wl,-rpath,{}", dynamic_library_path);
-    } else if env::consts::OS == "linux" {
-        let dynamic_library_path = "unkonwn_linux";
+    // where the ultraleap crate looks for LeapC by default, see its build script
+    println!("cargo:rerun-if-env-changed=LEAPSDK_DIR");
+    let dynamic_library_path = match env::var("LEAPSDK_DIR") {
+        Ok(leap_sdk_dir) => format!("{}/lib", leap_sdk_dir),
+        Err(_) if env::consts::OS == "macos" => {
+            "/Applications/Ultraleap Hand Tracking.app/Contents/LeapSDK/lib".to_string()
+        }
+        Err(_) if env::consts::OS == "linux" => {
+            "/usr/lib/ultraleap-hand-tracking-service".to_string()
+        }
+        Err(_) => return,
+    };
+    if env::consts::OS != "windows" {
         println!("cargo:rustc-link-arg=-Wl,-rpath,{}", dynamic_library_path);
     }
 }
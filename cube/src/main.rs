@@ -1,7 +1,8 @@
 use bevy::pbr::wireframe::{Wireframe, WireframePlugin};
 use bevy::prelude::*;
 use bevy::render::camera::ClearColorConfig;
-use ultraleap::LeapController;
+use std::env;
+use ultraleap::{DeliveryPolicy, HandTrackingSource, LeapController, PlaybackController};
 
 const TRANSLATION_FACTOR: f32 = 0.025;
 const Y_OFFSET: f32 = 150.0;
@@ -21,8 +22,19 @@ fn main() {
 struct Cube;
 
 fn setup_ultraleap(world: &mut World) {
-    let leap_controller = LeapController::new();
-    world.insert_non_send_resource(leap_controller);
+    // replays a recording instead of the device when ULTRALEAP_PLAYBACK is set
+    let source: Box<dyn HandTrackingSource> = match env::var("ULTRALEAP_PLAYBACK") {
+        Ok(path) => {
+            let playback_controller = PlaybackController::open(&path)
+                .unwrap_or_else(|error| panic!("failed to open recording {}: {}", path, error));
+            playback_controller.set_looping(true);
+            Box::new(playback_controller)
+        }
+        Err(_) => Box::new(LeapController::new()),
+    };
+    // one frame is consumed per update, older frames are of no use
+    source.set_delivery_policy(DeliveryPolicy::LatestOnly);
+    world.insert_non_send_resource(source);
 }
 
 fn spawn_cube(
@@ -70,16 +82,16 @@ fn cube_movement(
     // keyboard_input: Res<Input<KeyCode>>,
     mut cube_query: Query<&mut Transform, With<Cube>>,
     // time: Res<Time>,
-    mut leap_controller: NonSendMut<LeapController>,
+    mut source: NonSendMut<Box<dyn HandTrackingSource>>,
 ) {
     if let Ok(mut transform) = cube_query.get_single_mut() {
-        if let Some(tracking_event) = leap_controller.get_tracking_event() {
+        if let Some(tracking_event) = source.get_tracking_event() {
             // at least one hand is active
             if !tracking_event.hands.is_empty() {
                 let hand = &tracking_event.hands[0];
                 let palm = &hand.palm;
-                let mut translation = Vec3::from_array(palm.position);
-                let rotation = Quat::from_array(palm.orientation);
+                let mut translation: Vec3 = palm.position.into();
+                let rotation: Quat = palm.orientation.into();
                 translation.y -= Y_OFFSET;
                 translation *= TRANSLATION_FACTOR;
                 transform.translation = translation;
@@ -2,7 +2,10 @@ use bevy::log::LogPlugin;
 use bevy::prelude::*;
 use bevy::{render::camera::ClearColorConfig, window::PrimaryWindow, window::WindowMode};
 use bevy_prototype_lyon::prelude::*;
-use ultraleap::LeapController;
+use std::env;
+use ultraleap::{
+    ConnectionStatus, DeliveryPolicy, HandTrackingSource, LeapController, PlaybackController,
+};
 
 fn main() {
     App::new()
@@ -27,8 +30,10 @@ fn main() {
         .add_systems(Startup, setup_ultraleap)
         .add_systems(Startup, spawn_camera)
         .add_systems(Startup, spawn_cursor)
+        .add_systems(Startup, spawn_status_text)
         // .add_systems(Startup, test_spline)
         .add_systems(Update, cursor_movement)
+        .add_systems(Update, update_status_text)
         // .add_systems(OnEnter(DrawState::Drawing), spawn_spline)
         // .add_systems(Update, draw)
         .add_systems(Update, draw_splines)
@@ -38,6 +43,9 @@ fn main() {
 #[derive(Component)]
 struct Cursor;
 
+#[derive(Component)]
+struct StatusText;
+
 #[derive(Component)]
 struct Spline {
     points: Vec<Vec2>,
@@ -51,8 +59,19 @@ enum DrawState {
 }
 
 fn setup_ultraleap(world: &mut World) {
-    let leap_controller = LeapController::new();
-    world.insert_non_send_resource(leap_controller);
+    // replays a recording instead of the device when ULTRALEAP_PLAYBACK is set
+    let source: Box<dyn HandTrackingSource> = match env::var("ULTRALEAP_PLAYBACK") {
+        Ok(path) => {
+            let playback_controller = PlaybackController::open(&path)
+                .unwrap_or_else(|error| panic!("failed to open recording {}: {}", path, error));
+            playback_controller.set_looping(true);
+            Box::new(playback_controller)
+        }
+        Err(_) => Box::new(LeapController::new()),
+    };
+    // one frame is consumed per update, older frames are of no use
+    source.set_delivery_policy(DeliveryPolicy::LatestOnly);
+    world.insert_non_send_resource(source);
 }
 
 fn spawn_camera(mut commands: Commands) {
@@ -83,17 +102,54 @@ fn spawn_cursor(mut commands: Commands) {
     ));
 }
 
+fn spawn_status_text(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..default()
+        }),
+        StatusText {},
+    ));
+}
+
+fn update_status_text(
+    mut text_query: Query<&mut Text, With<StatusText>>,
+    source: NonSend<Box<dyn HandTrackingSource>>,
+) {
+    if let Ok(mut text) = text_query.get_single_mut() {
+        let status = match source.connection_status() {
+            ConnectionStatus::Connected => "",
+            ConnectionStatus::NotConnected => "Ultraleap service not running",
+            ConnectionStatus::HandshakeIncomplete => "connecting to Ultraleap service...",
+            ConnectionStatus::Lost => "connection to Ultraleap service lost",
+        };
+        if text.sections[0].value != status {
+            text.sections[0].value = status.to_string();
+        }
+    }
+}
+
 fn cursor_movement(
     mut commands: Commands,
     mut cursor_query: Query<(&mut Transform, &mut Fill), With<Cursor>>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     mut spline_query: Query<&mut Spline>,
-    mut leap_controller: NonSendMut<LeapController>,
+    mut source: NonSendMut<Box<dyn HandTrackingSource>>,
     drawing_current_state: Res<State<DrawState>>,
     mut drawing_next_state: ResMut<NextState<DrawState>>,
 ) {
     if let Ok((mut transform, mut fill)) = cursor_query.get_single_mut() {
-        if let Some(tracking_event) = leap_controller.get_tracking_event() {
+        if let Some(tracking_event) = source.get_tracking_event() {
             let window = window_query.get_single().unwrap();
 
             // at least one hand is active
@@ -106,12 +162,12 @@ fn cursor_movement(
                         .normalize_point(hand.index.distal.next_joint);
                     trace!("index finger tip position (normalized): {:?}", translation);
                     transform.translation = Vec3::new(
-                        translation[0] * (window.width() / 2.0),
-                        translation[1] * (window.height() / 2.0),
+                        translation.x * (window.width() / 2.0),
+                        translation.y * (window.height() / 2.0),
                         0.0,
                     );
 
-                    let z = translation[2];
+                    let z = translation.z;
                     let alpha = (z.clamp(0.0, 1.0) - 1.0).abs();
                     if z > 0.0 {
                         // hovering
@@ -147,8 +203,8 @@ fn cursor_movement(
     }
 }
 
-// fn draw(mut leap_controller: NonSendMut<LeapController>) {
-//     if let Some(tracking_event) = leap_controller.get_tracking_event() {
+// fn draw(mut source: NonSendMut<Box<dyn HandTrackingSource>>) {
+//     if let Some(tracking_event) = source.get_tracking_event() {
 //         // at least one hand is active
 //         if !tracking_event.hands.is_empty() {
 //             let hand = &tracking_event.hands[0];
@@ -0,0 +1,3865 @@
+// Bindings for the whole LeapC 5 API, laid out as bindgen 0.68.1 writes them
+// for `bindgen::Builder::default().header("LeapC.h")` with no other options,
+// including its layout tests. The `dynamic-loading` feature additionally
+// blocklists the `Leap.*` functions when generating, this file keeps them.
+//
+// Not generated from an SDK yet: the declarations were transcribed from the
+// LeapC 5 documentation and the expected values of the layout tests computed
+// by a C compiler from the same declarations, so the tests guard the Rust
+// layout against the documented one but not against the shipped header.
+// Selected by the `prebuilt-bindings` feature so building needs neither the
+// SDK headers nor libclang. To replace it, build with the `bindgen` feature
+// against the SDK, copy the `bindings.rs` it writes to the build output
+// directory here and record the SDK version it was generated from in this
+// comment.
+
+pub const LEAP_DISTORTION_MATRIX_N: u32 = 64;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CONNECTION {
+    _unused: [u8; 0],
+}
+pub type LEAP_CONNECTION = *mut _LEAP_CONNECTION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DEVICE {
+    _unused: [u8; 0],
+}
+pub type LEAP_DEVICE = *mut _LEAP_DEVICE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CALIBRATION {
+    _unused: [u8; 0],
+}
+pub type LEAP_CALIBRATION = *mut _LEAP_CALIBRATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CLOCK_REBASER {
+    _unused: [u8; 0],
+}
+pub type LEAP_CLOCK_REBASER = *mut _LEAP_CLOCK_REBASER;
+
+pub const _eLeapRS_eLeapRS_Success: _eLeapRS = 0;
+pub const _eLeapRS_eLeapRS_UnknownError: _eLeapRS = 3791716352;
+pub const _eLeapRS_eLeapRS_InvalidArgument: _eLeapRS = 3791716353;
+pub const _eLeapRS_eLeapRS_InsufficientResources: _eLeapRS = 3791716354;
+pub const _eLeapRS_eLeapRS_InsufficientBuffer: _eLeapRS = 3791716355;
+pub const _eLeapRS_eLeapRS_Timeout: _eLeapRS = 3791716356;
+pub const _eLeapRS_eLeapRS_NotConnected: _eLeapRS = 3791716357;
+pub const _eLeapRS_eLeapRS_HandshakeIncomplete: _eLeapRS = 3791716358;
+pub const _eLeapRS_eLeapRS_BufferSizeOverflow: _eLeapRS = 3791716359;
+pub const _eLeapRS_eLeapRS_ProtocolError: _eLeapRS = 3791716360;
+pub const _eLeapRS_eLeapRS_InvalidClientID: _eLeapRS = 3791716361;
+pub const _eLeapRS_eLeapRS_UnexpectedClosed: _eLeapRS = 3791716362;
+pub const _eLeapRS_eLeapRS_UnknownImageFrameRequest: _eLeapRS = 3791716363;
+pub const _eLeapRS_eLeapRS_UnknownTrackingFrameID: _eLeapRS = 3791716364;
+pub const _eLeapRS_eLeapRS_RoutineIsNotSeer: _eLeapRS = 3791716365;
+pub const _eLeapRS_eLeapRS_TimestampTooEarly: _eLeapRS = 3791716366;
+pub const _eLeapRS_eLeapRS_ConcurrentPoll: _eLeapRS = 3791716367;
+pub const _eLeapRS_eLeapRS_NotAvailable: _eLeapRS = 3875602434;
+pub const _eLeapRS_eLeapRS_NotStreaming: _eLeapRS = 3875602436;
+pub const _eLeapRS_eLeapRS_CannotOpenDevice: _eLeapRS = 3875602437;
+pub const _eLeapRS_eLeapRS_Unsupported: _eLeapRS = 3875602438;
+pub type _eLeapRS = ::std::os::raw::c_uint;
+pub use self::_eLeapRS as eLeapRS;
+
+pub const _eLeapConnectionConfig_eLeapConnectionConfig_MultiDeviceAware: _eLeapConnectionConfig = 1;
+pub type _eLeapConnectionConfig = ::std::os::raw::c_uint;
+pub use self::_eLeapConnectionConfig as eLeapConnectionConfig;
+
+pub const _eLeapTrackingMode_eLeapTrackingMode_Desktop: _eLeapTrackingMode = 0;
+pub const _eLeapTrackingMode_eLeapTrackingMode_HMD: _eLeapTrackingMode = 1;
+pub const _eLeapTrackingMode_eLeapTrackingMode_ScreenTop: _eLeapTrackingMode = 2;
+pub const _eLeapTrackingMode_eLeapTrackingMode_Unknown: _eLeapTrackingMode = 3;
+pub type _eLeapTrackingMode = ::std::os::raw::c_uint;
+pub use self::_eLeapTrackingMode as eLeapTrackingMode;
+
+extern "C" {
+    pub fn LeapGetNow() -> i64;
+}
+extern "C" {
+    pub fn LeapCreateClockRebaser(phClockRebaser: *mut LEAP_CLOCK_REBASER) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapUpdateRebase(
+        hClockRebaser: LEAP_CLOCK_REBASER,
+        userClock: i64,
+        leapClock: i64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRebaseClock(
+        hClockRebaser: LEAP_CLOCK_REBASER,
+        userClock: i64,
+        pLeapClock: *mut i64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapDestroyClockRebaser(hClockRebaser: LEAP_CLOCK_REBASER);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CONNECTION_CONFIG {
+    pub size: u32,
+    pub flags: u32,
+    pub server_namespace: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONNECTION_CONFIG() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONNECTION_CONFIG> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONNECTION_CONFIG>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_CONNECTION_CONFIG))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONNECTION_CONFIG>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONNECTION_CONFIG))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_CONFIG),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_CONFIG),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).server_namespace) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_CONFIG),
+            "::",
+            stringify!(server_namespace)
+        )
+    );
+}
+pub type LEAP_CONNECTION_CONFIG = _LEAP_CONNECTION_CONFIG;
+
+extern "C" {
+    pub fn LeapCreateConnection(
+        pConfig: *const LEAP_CONNECTION_CONFIG,
+        phConnection: *mut LEAP_CONNECTION,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapOpenConnection(hConnection: LEAP_CONNECTION) -> eLeapRS;
+}
+
+pub const _eLeapAllocatorType_eLeapAllocatorType_Int8: _eLeapAllocatorType = 0;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Uint8: _eLeapAllocatorType = 1;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Int16: _eLeapAllocatorType = 2;
+pub const _eLeapAllocatorType_eLeapAllocatorType_UInt16: _eLeapAllocatorType = 3;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Int32: _eLeapAllocatorType = 4;
+pub const _eLeapAllocatorType_eLeapAllocatorType_UInt32: _eLeapAllocatorType = 5;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Float: _eLeapAllocatorType = 6;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Int64: _eLeapAllocatorType = 8;
+pub const _eLeapAllocatorType_eLeapAllocatorType_UInt64: _eLeapAllocatorType = 9;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Double: _eLeapAllocatorType = 10;
+pub const _eLeapAllocatorType_eLeapAllocatorType_Pointer: _eLeapAllocatorType = 11;
+pub type _eLeapAllocatorType = ::std::os::raw::c_uint;
+pub use self::_eLeapAllocatorType as eLeapAllocatorType;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_ALLOCATOR {
+    pub allocate: ::std::option::Option<
+        unsafe extern "C" fn(
+            size: u32,
+            typeHint: eLeapAllocatorType,
+            state: *mut ::std::os::raw::c_void,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub deallocate: ::std::option::Option<
+        unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void, state: *mut ::std::os::raw::c_void),
+    >,
+    pub state: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout__LEAP_ALLOCATOR() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_ALLOCATOR> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_ALLOCATOR>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_ALLOCATOR))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_ALLOCATOR>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_ALLOCATOR))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).allocate) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_ALLOCATOR),
+            "::",
+            stringify!(allocate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).deallocate) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_ALLOCATOR),
+            "::",
+            stringify!(deallocate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_ALLOCATOR),
+            "::",
+            stringify!(state)
+        )
+    );
+}
+pub type LEAP_ALLOCATOR = _LEAP_ALLOCATOR;
+
+extern "C" {
+    pub fn LeapSetAllocator(
+        hConnection: LEAP_CONNECTION,
+        allocator: *const LEAP_ALLOCATOR,
+    ) -> eLeapRS;
+}
+
+pub const _eLeapServiceDisposition_eLeapServiceState_LowFpsDetected: _eLeapServiceDisposition = 1;
+pub const _eLeapServiceDisposition_eLeapServiceState_PoorPerformancePause:
+    _eLeapServiceDisposition = 2;
+pub const _eLeapServiceDisposition_eLeapServiceState_TrackingErrorUnknown:
+    _eLeapServiceDisposition = 4;
+pub const _eLeapServiceDisposition_eLeapServiceState_ALL: _eLeapServiceDisposition = 7;
+pub type _eLeapServiceDisposition = ::std::os::raw::c_uint;
+pub use self::_eLeapServiceDisposition as eLeapServiceDisposition;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CONNECTION_EVENT {
+    pub flags: eLeapServiceDisposition,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONNECTION_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONNECTION_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONNECTION_EVENT>(),
+        4usize,
+        concat!("Size of: ", stringify!(_LEAP_CONNECTION_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONNECTION_EVENT>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONNECTION_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_EVENT),
+            "::",
+            stringify!(flags)
+        )
+    );
+}
+pub type LEAP_CONNECTION_EVENT = _LEAP_CONNECTION_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CONNECTION_LOST_EVENT {
+    pub flags: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONNECTION_LOST_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONNECTION_LOST_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONNECTION_LOST_EVENT>(),
+        4usize,
+        concat!("Size of: ", stringify!(_LEAP_CONNECTION_LOST_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONNECTION_LOST_EVENT>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONNECTION_LOST_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_LOST_EVENT),
+            "::",
+            stringify!(flags)
+        )
+    );
+}
+pub type LEAP_CONNECTION_LOST_EVENT = _LEAP_CONNECTION_LOST_EVENT;
+
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_Streaming: _eLeapDeviceStatus = 1;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_Paused: _eLeapDeviceStatus = 2;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_Robust: _eLeapDeviceStatus = 4;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_Smudged: _eLeapDeviceStatus = 8;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_LowResource: _eLeapDeviceStatus = 16;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_UnknownFailure: _eLeapDeviceStatus = 3892379648;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_BadCalibration: _eLeapDeviceStatus = 3892379649;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_BadFirmware: _eLeapDeviceStatus = 3892379650;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_BadTransport: _eLeapDeviceStatus = 3892379651;
+pub const _eLeapDeviceStatus_eLeapDeviceStatus_BadControl: _eLeapDeviceStatus = 3892379652;
+pub type _eLeapDeviceStatus = ::std::os::raw::c_uint;
+pub use self::_eLeapDeviceStatus as eLeapDeviceStatus;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DEVICE_REF {
+    pub handle: *mut ::std::os::raw::c_void,
+    pub id: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DEVICE_REF() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DEVICE_REF> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DEVICE_REF>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_DEVICE_REF))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DEVICE_REF>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_DEVICE_REF))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).handle) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_REF),
+            "::",
+            stringify!(handle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_REF),
+            "::",
+            stringify!(id)
+        )
+    );
+}
+pub type LEAP_DEVICE_REF = _LEAP_DEVICE_REF;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DEVICE_EVENT {
+    pub flags: u32,
+    pub device: LEAP_DEVICE_REF,
+    pub status: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DEVICE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DEVICE_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DEVICE_EVENT>(),
+        32usize,
+        concat!("Size of: ", stringify!(_LEAP_DEVICE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DEVICE_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_DEVICE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_EVENT),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_EVENT),
+            "::",
+            stringify!(device)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_EVENT),
+            "::",
+            stringify!(status)
+        )
+    );
+}
+pub type LEAP_DEVICE_EVENT = _LEAP_DEVICE_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DEVICE_STATUS_CHANGE_EVENT {
+    pub device: LEAP_DEVICE_REF,
+    pub last_status: u32,
+    pub status: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DEVICE_STATUS_CHANGE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DEVICE_STATUS_CHANGE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DEVICE_STATUS_CHANGE_EVENT>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_DEVICE_STATUS_CHANGE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DEVICE_STATUS_CHANGE_EVENT>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_DEVICE_STATUS_CHANGE_EVENT)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_STATUS_CHANGE_EVENT),
+            "::",
+            stringify!(device)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_status) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_STATUS_CHANGE_EVENT),
+            "::",
+            stringify!(last_status)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_STATUS_CHANGE_EVENT),
+            "::",
+            stringify!(status)
+        )
+    );
+}
+pub type LEAP_DEVICE_STATUS_CHANGE_EVENT = _LEAP_DEVICE_STATUS_CHANGE_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DEVICE_FAILURE_EVENT {
+    pub status: eLeapDeviceStatus,
+    pub hDevice: LEAP_DEVICE,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DEVICE_FAILURE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DEVICE_FAILURE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DEVICE_FAILURE_EVENT>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_DEVICE_FAILURE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DEVICE_FAILURE_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_DEVICE_FAILURE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_FAILURE_EVENT),
+            "::",
+            stringify!(status)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hDevice) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_FAILURE_EVENT),
+            "::",
+            stringify!(hDevice)
+        )
+    );
+}
+pub type LEAP_DEVICE_FAILURE_EVENT = _LEAP_DEVICE_FAILURE_EVENT;
+
+pub const _eLeapDevicePID_eLeapDevicePID_Unknown: _eLeapDevicePID = 0;
+pub const _eLeapDevicePID_eLeapDevicePID_Peripheral: _eLeapDevicePID = 3;
+pub const _eLeapDevicePID_eLeapDevicePID_Dragonfly: _eLeapDevicePID = 4354;
+pub const _eLeapDevicePID_eLeapDevicePID_Nightcrawler: _eLeapDevicePID = 4609;
+pub const _eLeapDevicePID_eLeapDevicePID_Rigel: _eLeapDevicePID = 4610;
+pub const _eLeapDevicePID_eLeapDevicePID_SIR170: _eLeapDevicePID = 4611;
+pub const _eLeapDevicePID_eLeapDevicePID_3Di: _eLeapDevicePID = 4612;
+pub const _eLeapDevicePID_eLeapDevicePID_LMC2: _eLeapDevicePID = 4614;
+pub const _eLeapDevicePID_eLeapDevicePID_Invalid: _eLeapDevicePID = 4294967295;
+pub type _eLeapDevicePID = ::std::os::raw::c_uint;
+pub use self::_eLeapDevicePID as eLeapDevicePID;
+
+pub const _eLeapDeviceCaps_eLeapDeviceCaps_Color: _eLeapDeviceCaps = 1;
+pub type _eLeapDeviceCaps = ::std::os::raw::c_uint;
+pub use self::_eLeapDeviceCaps as eLeapDeviceCaps;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DEVICE_INFO {
+    pub size: u32,
+    pub status: u32,
+    pub caps: u32,
+    pub pid: eLeapDevicePID,
+    pub baseline: u32,
+    pub serial_length: u32,
+    pub serial: *mut ::std::os::raw::c_char,
+    pub h_fov: f32,
+    pub v_fov: f32,
+    pub range: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DEVICE_INFO() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DEVICE_INFO> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DEVICE_INFO>(),
+        48usize,
+        concat!("Size of: ", stringify!(_LEAP_DEVICE_INFO))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DEVICE_INFO>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_DEVICE_INFO))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(status)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).caps) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(caps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pid) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(pid)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).baseline) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(baseline)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).serial_length) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(serial_length)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).serial) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(serial)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h_fov) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(h_fov)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).v_fov) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(v_fov)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).range) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DEVICE_INFO),
+            "::",
+            stringify!(range)
+        )
+    );
+}
+pub type LEAP_DEVICE_INFO = _LEAP_DEVICE_INFO;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_FRAME_HEADER {
+    pub reserved: *mut ::std::os::raw::c_void,
+    pub frame_id: i64,
+    pub timestamp: i64,
+}
+#[test]
+fn bindgen_test_layout__LEAP_FRAME_HEADER() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_FRAME_HEADER> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_FRAME_HEADER>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_FRAME_HEADER))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_FRAME_HEADER>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_FRAME_HEADER))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reserved) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_FRAME_HEADER),
+            "::",
+            stringify!(reserved)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_id) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_FRAME_HEADER),
+            "::",
+            stringify!(frame_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_FRAME_HEADER),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+}
+pub type LEAP_FRAME_HEADER = _LEAP_FRAME_HEADER;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_VECTOR {
+    pub __bindgen_anon_1: _LEAP_VECTOR__bindgen_ty_1,
+}
+#[test]
+fn bindgen_test_layout__LEAP_VECTOR() {
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_VECTOR>(),
+        12usize,
+        concat!("Size of: ", stringify!(_LEAP_VECTOR))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_VECTOR>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_VECTOR))
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _LEAP_VECTOR__bindgen_ty_1 {
+    pub v: [f32; 3usize],
+    pub __bindgen_anon_1: _LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1,
+}
+#[test]
+fn bindgen_test_layout__LEAP_VECTOR__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_VECTOR__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_VECTOR__bindgen_ty_1>(),
+        12usize,
+        concat!("Size of: ", stringify!(_LEAP_VECTOR__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_VECTOR__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_VECTOR__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VECTOR__bindgen_ty_1),
+            "::",
+            stringify!(v)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1>(),
+        12usize,
+        concat!(
+            "Size of: ",
+            stringify!(_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1>(),
+        4usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VECTOR__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(z)
+        )
+    );
+}
+pub type LEAP_VECTOR = _LEAP_VECTOR;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_QUATERNION {
+    pub __bindgen_anon_1: _LEAP_QUATERNION__bindgen_ty_1,
+}
+#[test]
+fn bindgen_test_layout__LEAP_QUATERNION() {
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_QUATERNION>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_QUATERNION))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_QUATERNION>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_QUATERNION))
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _LEAP_QUATERNION__bindgen_ty_1 {
+    pub v: [f32; 4usize],
+    pub __bindgen_anon_1: _LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1,
+}
+#[test]
+fn bindgen_test_layout__LEAP_QUATERNION__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_QUATERNION__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_QUATERNION__bindgen_ty_1>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_QUATERNION__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_QUATERNION__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_QUATERNION__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1),
+            "::",
+            stringify!(v)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1>(),
+        16usize,
+        concat!(
+            "Size of: ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1>(),
+        4usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).z) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(z)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_QUATERNION__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(w)
+        )
+    );
+}
+pub type LEAP_QUATERNION = _LEAP_QUATERNION;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_MATRIX_3x3 {
+    pub m: [LEAP_VECTOR; 3usize],
+}
+#[test]
+fn bindgen_test_layout__LEAP_MATRIX_3x3() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_MATRIX_3x3> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_MATRIX_3x3>(),
+        36usize,
+        concat!("Size of: ", stringify!(_LEAP_MATRIX_3x3))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_MATRIX_3x3>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_MATRIX_3x3))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).m) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_MATRIX_3x3),
+            "::",
+            stringify!(m)
+        )
+    );
+}
+pub type LEAP_MATRIX_3x3 = _LEAP_MATRIX_3x3;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_BONE {
+    pub prev_joint: LEAP_VECTOR,
+    pub next_joint: LEAP_VECTOR,
+    pub width: f32,
+    pub rotation: LEAP_QUATERNION,
+}
+#[test]
+fn bindgen_test_layout__LEAP_BONE() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_BONE> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_BONE>(),
+        44usize,
+        concat!("Size of: ", stringify!(_LEAP_BONE))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_BONE>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_BONE))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prev_joint) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_BONE),
+            "::",
+            stringify!(prev_joint)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).next_joint) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_BONE),
+            "::",
+            stringify!(next_joint)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_BONE),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rotation) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_BONE),
+            "::",
+            stringify!(rotation)
+        )
+    );
+}
+pub type LEAP_BONE = _LEAP_BONE;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_DIGIT {
+    pub finger_id: i32,
+    pub __bindgen_anon_1: _LEAP_DIGIT__bindgen_ty_1,
+    pub is_extended: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DIGIT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DIGIT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DIGIT>(),
+        184usize,
+        concat!("Size of: ", stringify!(_LEAP_DIGIT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DIGIT>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_DIGIT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).finger_id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT),
+            "::",
+            stringify!(finger_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).is_extended) as usize - ptr as usize },
+        180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT),
+            "::",
+            stringify!(is_extended)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _LEAP_DIGIT__bindgen_ty_1 {
+    pub __bindgen_anon_1: _LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1,
+    pub bones: [LEAP_BONE; 4usize],
+}
+#[test]
+fn bindgen_test_layout__LEAP_DIGIT__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DIGIT__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DIGIT__bindgen_ty_1>(),
+        176usize,
+        concat!("Size of: ", stringify!(_LEAP_DIGIT__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DIGIT__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_DIGIT__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bones) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1),
+            "::",
+            stringify!(bones)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1 {
+    pub metacarpal: LEAP_BONE,
+    pub proximal: LEAP_BONE,
+    pub intermediate: LEAP_BONE,
+    pub distal: LEAP_BONE,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1>(),
+        176usize,
+        concat!(
+            "Size of: ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1>(),
+        4usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).metacarpal) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(metacarpal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).proximal) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(proximal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).intermediate) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(intermediate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).distal) as usize - ptr as usize },
+        132usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DIGIT__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(distal)
+        )
+    );
+}
+pub type LEAP_DIGIT = _LEAP_DIGIT;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_PALM {
+    pub position: LEAP_VECTOR,
+    pub stabilized_position: LEAP_VECTOR,
+    pub velocity: LEAP_VECTOR,
+    pub normal: LEAP_VECTOR,
+    pub width: f32,
+    pub direction: LEAP_VECTOR,
+    pub orientation: LEAP_QUATERNION,
+}
+#[test]
+fn bindgen_test_layout__LEAP_PALM() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_PALM> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_PALM>(),
+        80usize,
+        concat!("Size of: ", stringify!(_LEAP_PALM))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_PALM>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_PALM))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).position) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(position)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stabilized_position) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(stabilized_position)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).velocity) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(velocity)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).normal) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(normal)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).direction) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(direction)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).orientation) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_PALM),
+            "::",
+            stringify!(orientation)
+        )
+    );
+}
+pub type LEAP_PALM = _LEAP_PALM;
+
+pub const _eLeapHandType_eLeapHandType_Left: _eLeapHandType = 0;
+pub const _eLeapHandType_eLeapHandType_Right: _eLeapHandType = 1;
+pub type _eLeapHandType = ::std::os::raw::c_uint;
+pub use self::_eLeapHandType as eLeapHandType;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_HAND {
+    pub id: u32,
+    pub flags: u32,
+    pub type_: eLeapHandType,
+    pub confidence: f32,
+    pub visible_time: u64,
+    pub pinch_distance: f32,
+    pub grab_angle: f32,
+    pub pinch_strength: f32,
+    pub grab_strength: f32,
+    pub palm: LEAP_PALM,
+    pub __bindgen_anon_1: _LEAP_HAND__bindgen_ty_1,
+    pub arm: LEAP_BONE,
+}
+#[test]
+fn bindgen_test_layout__LEAP_HAND() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_HAND> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_HAND>(),
+        1088usize,
+        concat!("Size of: ", stringify!(_LEAP_HAND))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_HAND>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_HAND))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).confidence) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(confidence)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).visible_time) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(visible_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pinch_distance) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(pinch_distance)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).grab_angle) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(grab_angle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pinch_strength) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(pinch_strength)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).grab_strength) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(grab_strength)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).palm) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(palm)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).arm) as usize - ptr as usize },
+        1040usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND),
+            "::",
+            stringify!(arm)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _LEAP_HAND__bindgen_ty_1 {
+    pub __bindgen_anon_1: _LEAP_HAND__bindgen_ty_1__bindgen_ty_1,
+    pub digits: [LEAP_DIGIT; 5usize],
+}
+#[test]
+fn bindgen_test_layout__LEAP_HAND__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_HAND__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_HAND__bindgen_ty_1>(),
+        920usize,
+        concat!("Size of: ", stringify!(_LEAP_HAND__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_HAND__bindgen_ty_1>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_HAND__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).digits) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1),
+            "::",
+            stringify!(digits)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_HAND__bindgen_ty_1__bindgen_ty_1 {
+    pub thumb: LEAP_DIGIT,
+    pub index: LEAP_DIGIT,
+    pub middle: LEAP_DIGIT,
+    pub ring: LEAP_DIGIT,
+    pub pinky: LEAP_DIGIT,
+}
+#[test]
+fn bindgen_test_layout__LEAP_HAND__bindgen_ty_1__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_HAND__bindgen_ty_1__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_HAND__bindgen_ty_1__bindgen_ty_1>(),
+        920usize,
+        concat!(
+            "Size of: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_HAND__bindgen_ty_1__bindgen_ty_1>(),
+        4usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thumb) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(thumb)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).index) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).middle) as usize - ptr as usize },
+        368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(middle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ring) as usize - ptr as usize },
+        552usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(ring)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pinky) as usize - ptr as usize },
+        736usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HAND__bindgen_ty_1__bindgen_ty_1),
+            "::",
+            stringify!(pinky)
+        )
+    );
+}
+pub type LEAP_HAND = _LEAP_HAND;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_TRACKING_EVENT {
+    pub info: LEAP_FRAME_HEADER,
+    pub tracking_frame_id: i64,
+    pub nHands: u32,
+    pub pHands: *mut LEAP_HAND,
+    pub framerate: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_TRACKING_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_TRACKING_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_TRACKING_EVENT>(),
+        56usize,
+        concat!("Size of: ", stringify!(_LEAP_TRACKING_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_TRACKING_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_TRACKING_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).info) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_EVENT),
+            "::",
+            stringify!(info)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).tracking_frame_id) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_EVENT),
+            "::",
+            stringify!(tracking_frame_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nHands) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_EVENT),
+            "::",
+            stringify!(nHands)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pHands) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_EVENT),
+            "::",
+            stringify!(pHands)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).framerate) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_EVENT),
+            "::",
+            stringify!(framerate)
+        )
+    );
+}
+pub type LEAP_TRACKING_EVENT = _LEAP_TRACKING_EVENT;
+
+pub const _eLeapLogSeverity_eLeapLogSeverity_Unknown: _eLeapLogSeverity = 0;
+pub const _eLeapLogSeverity_eLeapLogSeverity_Critical: _eLeapLogSeverity = 1;
+pub const _eLeapLogSeverity_eLeapLogSeverity_Warning: _eLeapLogSeverity = 2;
+pub const _eLeapLogSeverity_eLeapLogSeverity_Information: _eLeapLogSeverity = 3;
+pub type _eLeapLogSeverity = ::std::os::raw::c_uint;
+pub use self::_eLeapLogSeverity as eLeapLogSeverity;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_LOG_EVENT {
+    pub severity: eLeapLogSeverity,
+    pub timestamp: i64,
+    pub message: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__LEAP_LOG_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_LOG_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_LOG_EVENT>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_LOG_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_LOG_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_LOG_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).severity) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_LOG_EVENT),
+            "::",
+            stringify!(severity)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_LOG_EVENT),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).message) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_LOG_EVENT),
+            "::",
+            stringify!(message)
+        )
+    );
+}
+pub type LEAP_LOG_EVENT = _LEAP_LOG_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_LOG_EVENTS {
+    pub nEvents: u32,
+    pub events: *mut LEAP_LOG_EVENT,
+}
+#[test]
+fn bindgen_test_layout__LEAP_LOG_EVENTS() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_LOG_EVENTS> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_LOG_EVENTS>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_LOG_EVENTS))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_LOG_EVENTS>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_LOG_EVENTS))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nEvents) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_LOG_EVENTS),
+            "::",
+            stringify!(nEvents)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).events) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_LOG_EVENTS),
+            "::",
+            stringify!(events)
+        )
+    );
+}
+pub type LEAP_LOG_EVENTS = _LEAP_LOG_EVENTS;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_POLICY_EVENT {
+    pub reserved: u32,
+    pub current_policy: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_POLICY_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_POLICY_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_POLICY_EVENT>(),
+        8usize,
+        concat!("Size of: ", stringify!(_LEAP_POLICY_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_POLICY_EVENT>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_POLICY_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reserved) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POLICY_EVENT),
+            "::",
+            stringify!(reserved)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).current_policy) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POLICY_EVENT),
+            "::",
+            stringify!(current_policy)
+        )
+    );
+}
+pub type LEAP_POLICY_EVENT = _LEAP_POLICY_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_TRACKING_MODE_EVENT {
+    pub reserved: u32,
+    pub current_tracking_mode: eLeapTrackingMode,
+}
+#[test]
+fn bindgen_test_layout__LEAP_TRACKING_MODE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_TRACKING_MODE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_TRACKING_MODE_EVENT>(),
+        8usize,
+        concat!("Size of: ", stringify!(_LEAP_TRACKING_MODE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_TRACKING_MODE_EVENT>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_TRACKING_MODE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reserved) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_MODE_EVENT),
+            "::",
+            stringify!(reserved)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).current_tracking_mode) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TRACKING_MODE_EVENT),
+            "::",
+            stringify!(current_tracking_mode)
+        )
+    );
+}
+pub type LEAP_TRACKING_MODE_EVENT = _LEAP_TRACKING_MODE_EVENT;
+
+pub const _eLeapValueType_eLeapValueType_Unknown: _eLeapValueType = 0;
+pub const _eLeapValueType_eLeapValueType_Boolean: _eLeapValueType = 1;
+pub const _eLeapValueType_eLeapValueType_Int32: _eLeapValueType = 2;
+pub const _eLeapValueType_eLeapValueType_Float: _eLeapValueType = 3;
+pub const _eLeapValueType_eLeapValueType_String: _eLeapValueType = 4;
+pub const _eLeapValueType_FORCE_DWORD: _eLeapValueType = 2147483647;
+pub type _eLeapValueType = ::std::os::raw::c_uint;
+pub use self::_eLeapValueType as eLeapValueType;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_VARIANT {
+    pub type_: eLeapValueType,
+    pub __bindgen_anon_1: _LEAP_VARIANT__bindgen_ty_1,
+}
+#[test]
+fn bindgen_test_layout__LEAP_VARIANT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_VARIANT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_VARIANT>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_VARIANT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_VARIANT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_VARIANT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VARIANT),
+            "::",
+            stringify!(type_)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _LEAP_VARIANT__bindgen_ty_1 {
+    pub boolValue: bool,
+    pub iValue: i32,
+    pub fValue: f32,
+    pub strValue: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__LEAP_VARIANT__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_VARIANT__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_VARIANT__bindgen_ty_1>(),
+        8usize,
+        concat!("Size of: ", stringify!(_LEAP_VARIANT__bindgen_ty_1))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_VARIANT__bindgen_ty_1>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_VARIANT__bindgen_ty_1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).boolValue) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VARIANT__bindgen_ty_1),
+            "::",
+            stringify!(boolValue)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iValue) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VARIANT__bindgen_ty_1),
+            "::",
+            stringify!(iValue)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fValue) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VARIANT__bindgen_ty_1),
+            "::",
+            stringify!(fValue)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).strValue) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VARIANT__bindgen_ty_1),
+            "::",
+            stringify!(strValue)
+        )
+    );
+}
+pub type LEAP_VARIANT = _LEAP_VARIANT;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_CONFIG_RESPONSE_EVENT {
+    pub requestID: u32,
+    pub value: LEAP_VARIANT,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONFIG_RESPONSE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONFIG_RESPONSE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONFIG_RESPONSE_EVENT>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_CONFIG_RESPONSE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONFIG_RESPONSE_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONFIG_RESPONSE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).requestID) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONFIG_RESPONSE_EVENT),
+            "::",
+            stringify!(requestID)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).value) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONFIG_RESPONSE_EVENT),
+            "::",
+            stringify!(value)
+        )
+    );
+}
+pub type LEAP_CONFIG_RESPONSE_EVENT = _LEAP_CONFIG_RESPONSE_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CONFIG_CHANGE_EVENT {
+    pub requestID: u32,
+    pub status: bool,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONFIG_CHANGE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONFIG_CHANGE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONFIG_CHANGE_EVENT>(),
+        8usize,
+        concat!("Size of: ", stringify!(_LEAP_CONFIG_CHANGE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONFIG_CHANGE_EVENT>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONFIG_CHANGE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).requestID) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONFIG_CHANGE_EVENT),
+            "::",
+            stringify!(requestID)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONFIG_CHANGE_EVENT),
+            "::",
+            stringify!(status)
+        )
+    );
+}
+pub type LEAP_CONFIG_CHANGE_EVENT = _LEAP_CONFIG_CHANGE_EVENT;
+
+extern "C" {
+    pub fn LeapSaveConfigValue(
+        hConnection: LEAP_CONNECTION,
+        key: *const ::std::os::raw::c_char,
+        value: *const LEAP_VARIANT,
+        pRequestID: *mut u32,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRequestConfigValue(
+        hConnection: LEAP_CONNECTION,
+        key: *const ::std::os::raw::c_char,
+        pRequestID: *mut u32,
+    ) -> eLeapRS;
+}
+
+pub const _eLeapDroppedFrameType_eLeapDroppedFrameType_PreprocessingQueue: _eLeapDroppedFrameType =
+    0;
+pub const _eLeapDroppedFrameType_eLeapDroppedFrameType_TrackingQueue: _eLeapDroppedFrameType = 1;
+pub const _eLeapDroppedFrameType_eLeapDroppedFrameType_Other: _eLeapDroppedFrameType = 2;
+pub type _eLeapDroppedFrameType = ::std::os::raw::c_uint;
+pub use self::_eLeapDroppedFrameType as eLeapDroppedFrameType;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DROPPED_FRAME_EVENT {
+    pub frame_id: i64,
+    pub type_: eLeapDroppedFrameType,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DROPPED_FRAME_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DROPPED_FRAME_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DROPPED_FRAME_EVENT>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_DROPPED_FRAME_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DROPPED_FRAME_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_DROPPED_FRAME_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DROPPED_FRAME_EVENT),
+            "::",
+            stringify!(frame_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DROPPED_FRAME_EVENT),
+            "::",
+            stringify!(type_)
+        )
+    );
+}
+pub type LEAP_DROPPED_FRAME_EVENT = _LEAP_DROPPED_FRAME_EVENT;
+
+pub const _eLeapImageType_eLeapImageType_UNKNOWN: _eLeapImageType = 0;
+pub const _eLeapImageType_eLeapImageType_Default: _eLeapImageType = 1;
+pub const _eLeapImageType_eLeapImageType_Raw: _eLeapImageType = 2;
+pub type _eLeapImageType = ::std::os::raw::c_uint;
+pub use self::_eLeapImageType as eLeapImageType;
+
+pub const _eLeapImageFormat_eLeapImageFormat_UNKNOWN: _eLeapImageFormat = 0;
+pub const _eLeapImageFormat_eLeapImageFormat_IR: _eLeapImageFormat = 3240521;
+pub const _eLeapImageFormat_eLeapImageFormat_RGBIr_Bayer: _eLeapImageFormat = 1229082439;
+pub type _eLeapImageFormat = ::std::os::raw::c_uint;
+pub use self::_eLeapImageFormat as eLeapImageFormat;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_IMAGE_PROPERTIES {
+    pub type_: eLeapImageType,
+    pub format: eLeapImageFormat,
+    pub bpp: u32,
+    pub width: u32,
+    pub height: u32,
+    pub x_scale: f32,
+    pub x_offset: f32,
+    pub y_scale: f32,
+    pub y_offset: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_IMAGE_PROPERTIES() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_IMAGE_PROPERTIES> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_IMAGE_PROPERTIES>(),
+        36usize,
+        concat!("Size of: ", stringify!(_LEAP_IMAGE_PROPERTIES))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_IMAGE_PROPERTIES>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_IMAGE_PROPERTIES))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(format)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bpp) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(bpp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).width) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(width)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).height) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(height)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x_scale) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(x_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x_offset) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(x_offset)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y_scale) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(y_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y_offset) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_PROPERTIES),
+            "::",
+            stringify!(y_offset)
+        )
+    );
+}
+pub type LEAP_IMAGE_PROPERTIES = _LEAP_IMAGE_PROPERTIES;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DISTORTION_MATRIX {
+    pub matrix: [[_LEAP_DISTORTION_MATRIX__bindgen_ty_1; 64usize]; 64usize],
+}
+#[test]
+fn bindgen_test_layout__LEAP_DISTORTION_MATRIX() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DISTORTION_MATRIX> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DISTORTION_MATRIX>(),
+        32768usize,
+        concat!("Size of: ", stringify!(_LEAP_DISTORTION_MATRIX))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DISTORTION_MATRIX>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_DISTORTION_MATRIX))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).matrix) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DISTORTION_MATRIX),
+            "::",
+            stringify!(matrix)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_DISTORTION_MATRIX__bindgen_ty_1 {
+    pub x: f32,
+    pub y: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_DISTORTION_MATRIX__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_DISTORTION_MATRIX__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_DISTORTION_MATRIX__bindgen_ty_1>(),
+        8usize,
+        concat!(
+            "Size of: ",
+            stringify!(_LEAP_DISTORTION_MATRIX__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_DISTORTION_MATRIX__bindgen_ty_1>(),
+        4usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_DISTORTION_MATRIX__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DISTORTION_MATRIX__bindgen_ty_1),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_DISTORTION_MATRIX__bindgen_ty_1),
+            "::",
+            stringify!(y)
+        )
+    );
+}
+pub type LEAP_DISTORTION_MATRIX = _LEAP_DISTORTION_MATRIX;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_IMAGE {
+    pub properties: LEAP_IMAGE_PROPERTIES,
+    pub matrix_version: u64,
+    pub distortion_matrix: *mut LEAP_DISTORTION_MATRIX,
+    pub data: *mut ::std::os::raw::c_void,
+    pub offset: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_IMAGE() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_IMAGE> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_IMAGE>(),
+        72usize,
+        concat!("Size of: ", stringify!(_LEAP_IMAGE))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_IMAGE>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_IMAGE))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).properties) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE),
+            "::",
+            stringify!(properties)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).matrix_version) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE),
+            "::",
+            stringify!(matrix_version)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).distortion_matrix) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE),
+            "::",
+            stringify!(distortion_matrix)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE),
+            "::",
+            stringify!(data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).offset) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE),
+            "::",
+            stringify!(offset)
+        )
+    );
+}
+pub type LEAP_IMAGE = _LEAP_IMAGE;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_IMAGE_EVENT {
+    pub info: LEAP_FRAME_HEADER,
+    pub image: [LEAP_IMAGE; 2usize],
+    pub calib: LEAP_CALIBRATION,
+}
+#[test]
+fn bindgen_test_layout__LEAP_IMAGE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_IMAGE_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_IMAGE_EVENT>(),
+        176usize,
+        concat!("Size of: ", stringify!(_LEAP_IMAGE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_IMAGE_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_IMAGE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).info) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_EVENT),
+            "::",
+            stringify!(info)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).image) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_EVENT),
+            "::",
+            stringify!(image)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).calib) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMAGE_EVENT),
+            "::",
+            stringify!(calib)
+        )
+    );
+}
+pub type LEAP_IMAGE_EVENT = _LEAP_IMAGE_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_POINT_MAPPING_CHANGE_EVENT {
+    pub info: LEAP_FRAME_HEADER,
+    pub frame_id: i64,
+    pub nPoints: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_POINT_MAPPING_CHANGE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_POINT_MAPPING_CHANGE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_POINT_MAPPING_CHANGE_EVENT>(),
+        40usize,
+        concat!("Size of: ", stringify!(_LEAP_POINT_MAPPING_CHANGE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_POINT_MAPPING_CHANGE_EVENT>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_POINT_MAPPING_CHANGE_EVENT)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).info) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING_CHANGE_EVENT),
+            "::",
+            stringify!(info)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_id) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING_CHANGE_EVENT),
+            "::",
+            stringify!(frame_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nPoints) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING_CHANGE_EVENT),
+            "::",
+            stringify!(nPoints)
+        )
+    );
+}
+pub type LEAP_POINT_MAPPING_CHANGE_EVENT = _LEAP_POINT_MAPPING_CHANGE_EVENT;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_POINT_MAPPING {
+    pub frame_id: i64,
+    pub timestamp: i64,
+    pub nPoints: u32,
+    pub pPoints: *mut LEAP_VECTOR,
+    pub pIDs: *mut u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_POINT_MAPPING() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_POINT_MAPPING> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_POINT_MAPPING>(),
+        40usize,
+        concat!("Size of: ", stringify!(_LEAP_POINT_MAPPING))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_POINT_MAPPING>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_POINT_MAPPING))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING),
+            "::",
+            stringify!(frame_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nPoints) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING),
+            "::",
+            stringify!(nPoints)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pPoints) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING),
+            "::",
+            stringify!(pPoints)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pIDs) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_POINT_MAPPING),
+            "::",
+            stringify!(pIDs)
+        )
+    );
+}
+pub type LEAP_POINT_MAPPING = _LEAP_POINT_MAPPING;
+
+extern "C" {
+    pub fn LeapGetPointMappingSize(hConnection: LEAP_CONNECTION, pSize: *mut u64) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetPointMapping(
+        hConnection: LEAP_CONNECTION,
+        pointMapping: *mut LEAP_POINT_MAPPING,
+        pSize: *mut u64,
+    ) -> eLeapRS;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_HEAD_POSE_EVENT {
+    pub timestamp: i64,
+    pub head_position: LEAP_VECTOR,
+    pub head_orientation: LEAP_QUATERNION,
+    pub head_linear_velocity: LEAP_VECTOR,
+    pub head_angular_velocity: LEAP_VECTOR,
+}
+#[test]
+fn bindgen_test_layout__LEAP_HEAD_POSE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_HEAD_POSE_EVENT> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_HEAD_POSE_EVENT>(),
+        64usize,
+        concat!("Size of: ", stringify!(_LEAP_HEAD_POSE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_HEAD_POSE_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_HEAD_POSE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HEAD_POSE_EVENT),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).head_position) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HEAD_POSE_EVENT),
+            "::",
+            stringify!(head_position)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).head_orientation) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HEAD_POSE_EVENT),
+            "::",
+            stringify!(head_orientation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).head_linear_velocity) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HEAD_POSE_EVENT),
+            "::",
+            stringify!(head_linear_velocity)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).head_angular_velocity) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_HEAD_POSE_EVENT),
+            "::",
+            stringify!(head_angular_velocity)
+        )
+    );
+}
+pub type LEAP_HEAD_POSE_EVENT = _LEAP_HEAD_POSE_EVENT;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_EYE_EVENT {
+    pub frame_id: i64,
+    pub timestamp: i64,
+    pub left_eye_position: LEAP_VECTOR,
+    pub right_eye_position: LEAP_VECTOR,
+    pub left_eye_estimated_error: f32,
+    pub right_eye_estimated_error: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_EYE_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_EYE_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_EYE_EVENT>(),
+        48usize,
+        concat!("Size of: ", stringify!(_LEAP_EYE_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_EYE_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_EYE_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).frame_id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_EYE_EVENT),
+            "::",
+            stringify!(frame_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_EYE_EVENT),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).left_eye_position) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_EYE_EVENT),
+            "::",
+            stringify!(left_eye_position)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).right_eye_position) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_EYE_EVENT),
+            "::",
+            stringify!(right_eye_position)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).left_eye_estimated_error) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_EYE_EVENT),
+            "::",
+            stringify!(left_eye_estimated_error)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).right_eye_estimated_error) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_EYE_EVENT),
+            "::",
+            stringify!(right_eye_estimated_error)
+        )
+    );
+}
+pub type LEAP_EYE_EVENT = _LEAP_EYE_EVENT;
+
+pub const _eLeapIMUFlag_eLeapIMUFlag_HasAccelerometer: _eLeapIMUFlag = 1;
+pub const _eLeapIMUFlag_eLeapIMUFlag_HasGyroscope: _eLeapIMUFlag = 2;
+pub const _eLeapIMUFlag_eLeapIMUFlag_HasTemperature: _eLeapIMUFlag = 4;
+pub type _eLeapIMUFlag = ::std::os::raw::c_uint;
+pub use self::_eLeapIMUFlag as eLeapIMUFlag;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_IMU_EVENT {
+    pub timestamp: i64,
+    pub timestamp_hw: i64,
+    pub flags: eLeapIMUFlag,
+    pub accelerometer: LEAP_VECTOR,
+    pub gyroscope: LEAP_VECTOR,
+    pub temperature: f32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_IMU_EVENT() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_IMU_EVENT> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_IMU_EVENT>(),
+        48usize,
+        concat!("Size of: ", stringify!(_LEAP_IMU_EVENT))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_IMU_EVENT>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_IMU_EVENT))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMU_EVENT),
+            "::",
+            stringify!(timestamp)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).timestamp_hw) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMU_EVENT),
+            "::",
+            stringify!(timestamp_hw)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMU_EVENT),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).accelerometer) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMU_EVENT),
+            "::",
+            stringify!(accelerometer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gyroscope) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMU_EVENT),
+            "::",
+            stringify!(gyroscope)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temperature) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_IMU_EVENT),
+            "::",
+            stringify!(temperature)
+        )
+    );
+}
+pub type LEAP_IMU_EVENT = _LEAP_IMU_EVENT;
+
+pub const _eLeapEventType_eLeapEventType_None: _eLeapEventType = 0;
+pub const _eLeapEventType_eLeapEventType_Connection: _eLeapEventType = 1;
+pub const _eLeapEventType_eLeapEventType_ConnectionLost: _eLeapEventType = 2;
+pub const _eLeapEventType_eLeapEventType_Device: _eLeapEventType = 3;
+pub const _eLeapEventType_eLeapEventType_DeviceFailure: _eLeapEventType = 4;
+pub const _eLeapEventType_eLeapEventType_Policy: _eLeapEventType = 5;
+pub const _eLeapEventType_eLeapEventType_Tracking: _eLeapEventType = 256;
+pub const _eLeapEventType_eLeapEventType_ImageRequestError: _eLeapEventType = 257;
+pub const _eLeapEventType_eLeapEventType_ImageComplete: _eLeapEventType = 258;
+pub const _eLeapEventType_eLeapEventType_LogEvent: _eLeapEventType = 259;
+pub const _eLeapEventType_eLeapEventType_DeviceLost: _eLeapEventType = 260;
+pub const _eLeapEventType_eLeapEventType_ConfigResponse: _eLeapEventType = 261;
+pub const _eLeapEventType_eLeapEventType_ConfigChange: _eLeapEventType = 262;
+pub const _eLeapEventType_eLeapEventType_DeviceStatusChange: _eLeapEventType = 263;
+pub const _eLeapEventType_eLeapEventType_DroppedFrame: _eLeapEventType = 264;
+pub const _eLeapEventType_eLeapEventType_Image: _eLeapEventType = 265;
+pub const _eLeapEventType_eLeapEventType_PointMappingChange: _eLeapEventType = 266;
+pub const _eLeapEventType_eLeapEventType_TrackingMode: _eLeapEventType = 267;
+pub const _eLeapEventType_eLeapEventType_LogEvents: _eLeapEventType = 268;
+pub const _eLeapEventType_eLeapEventType_HeadPose: _eLeapEventType = 269;
+pub const _eLeapEventType_eLeapEventType_Eyes: _eLeapEventType = 270;
+pub const _eLeapEventType_eLeapEventType_IMU: _eLeapEventType = 271;
+pub type _eLeapEventType = ::std::os::raw::c_uint;
+pub use self::_eLeapEventType as eLeapEventType;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LEAP_CONNECTION_MESSAGE {
+    pub size: u32,
+    pub type_: eLeapEventType,
+    pub __bindgen_anon_1: _LEAP_CONNECTION_MESSAGE__bindgen_ty_1,
+    pub device_id: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONNECTION_MESSAGE() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONNECTION_MESSAGE> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONNECTION_MESSAGE>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_CONNECTION_MESSAGE))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONNECTION_MESSAGE>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONNECTION_MESSAGE))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_id) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE),
+            "::",
+            stringify!(device_id)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _LEAP_CONNECTION_MESSAGE__bindgen_ty_1 {
+    pub pointer: *const ::std::os::raw::c_void,
+    pub connection_event: *const LEAP_CONNECTION_EVENT,
+    pub connection_lost_event: *const LEAP_CONNECTION_LOST_EVENT,
+    pub device_event: *const LEAP_DEVICE_EVENT,
+    pub device_status_change_event: *const LEAP_DEVICE_STATUS_CHANGE_EVENT,
+    pub policy_event: *const LEAP_POLICY_EVENT,
+    pub device_failure_event: *const LEAP_DEVICE_FAILURE_EVENT,
+    pub tracking_event: *const LEAP_TRACKING_EVENT,
+    pub tracking_mode_event: *const LEAP_TRACKING_MODE_EVENT,
+    pub log_event: *const LEAP_LOG_EVENT,
+    pub log_events: *const LEAP_LOG_EVENTS,
+    pub config_response_event: *const LEAP_CONFIG_RESPONSE_EVENT,
+    pub config_change_event: *const LEAP_CONFIG_CHANGE_EVENT,
+    pub dropped_frame_event: *const LEAP_DROPPED_FRAME_EVENT,
+    pub image_event: *const LEAP_IMAGE_EVENT,
+    pub point_mapping_change_event: *const LEAP_POINT_MAPPING_CHANGE_EVENT,
+    pub head_pose_event: *const LEAP_HEAD_POSE_EVENT,
+    pub eye_event: *const LEAP_EYE_EVENT,
+    pub imu_event: *const LEAP_IMU_EVENT,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONNECTION_MESSAGE__bindgen_ty_1() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONNECTION_MESSAGE__bindgen_ty_1> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONNECTION_MESSAGE__bindgen_ty_1>(),
+        8usize,
+        concat!(
+            "Size of: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONNECTION_MESSAGE__bindgen_ty_1>(),
+        8usize,
+        concat!(
+            "Alignment of ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pointer) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(pointer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).connection_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(connection_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).connection_lost_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(connection_lost_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(device_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_status_change_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(device_status_change_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).policy_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(policy_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_failure_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(device_failure_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).tracking_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(tracking_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).tracking_mode_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(tracking_mode_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(log_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log_events) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(log_events)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).config_response_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(config_response_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).config_change_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(config_change_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dropped_frame_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(dropped_frame_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).image_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(image_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).point_mapping_change_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(point_mapping_change_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).head_pose_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(head_pose_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).eye_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(eye_event)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).imu_event) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_MESSAGE__bindgen_ty_1),
+            "::",
+            stringify!(imu_event)
+        )
+    );
+}
+pub type LEAP_CONNECTION_MESSAGE = _LEAP_CONNECTION_MESSAGE;
+
+extern "C" {
+    pub fn LeapPollConnection(
+        hConnection: LEAP_CONNECTION,
+        timeout: u32,
+        evt: *mut LEAP_CONNECTION_MESSAGE,
+    ) -> eLeapRS;
+}
+
+pub const _eLeapConnectionStatus_eLeapConnectionStatus_NotConnected: _eLeapConnectionStatus = 0;
+pub const _eLeapConnectionStatus_eLeapConnectionStatus_Connected: _eLeapConnectionStatus = 1;
+pub const _eLeapConnectionStatus_eLeapConnectionStatus_HandshakeIncomplete: _eLeapConnectionStatus =
+    3842048001;
+pub const _eLeapConnectionStatus_eLeapConnectionStatus_NotRunning: _eLeapConnectionStatus =
+    3875733508;
+pub type _eLeapConnectionStatus = ::std::os::raw::c_uint;
+pub use self::_eLeapConnectionStatus as eLeapConnectionStatus;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_CONNECTION_INFO {
+    pub size: u32,
+    pub status: eLeapConnectionStatus,
+}
+#[test]
+fn bindgen_test_layout__LEAP_CONNECTION_INFO() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_CONNECTION_INFO> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_CONNECTION_INFO>(),
+        8usize,
+        concat!("Size of: ", stringify!(_LEAP_CONNECTION_INFO))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_CONNECTION_INFO>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_CONNECTION_INFO))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_INFO),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).status) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_CONNECTION_INFO),
+            "::",
+            stringify!(status)
+        )
+    );
+}
+pub type LEAP_CONNECTION_INFO = _LEAP_CONNECTION_INFO;
+
+extern "C" {
+    pub fn LeapGetConnectionInfo(
+        hConnection: LEAP_CONNECTION,
+        pInfo: *mut LEAP_CONNECTION_INFO,
+    ) -> eLeapRS;
+}
+
+pub const _eLeapPolicyFlag_eLeapPolicyFlag_BackgroundFrames: _eLeapPolicyFlag = 1;
+pub const _eLeapPolicyFlag_eLeapPolicyFlag_Images: _eLeapPolicyFlag = 2;
+pub const _eLeapPolicyFlag_eLeapPolicyFlag_OptimizeHMD: _eLeapPolicyFlag = 4;
+pub const _eLeapPolicyFlag_eLeapPolicyFlag_AllowPauseResume: _eLeapPolicyFlag = 8;
+pub const _eLeapPolicyFlag_eLeapPolicyFlag_MapPoints: _eLeapPolicyFlag = 128;
+pub const _eLeapPolicyFlag_eLeapPolicyFlag_OptimizeScreenTop: _eLeapPolicyFlag = 256;
+pub type _eLeapPolicyFlag = ::std::os::raw::c_uint;
+pub use self::_eLeapPolicyFlag as eLeapPolicyFlag;
+
+extern "C" {
+    pub fn LeapSetPolicyFlags(hConnection: LEAP_CONNECTION, set: u64, clear: u64) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapSetPolicyFlagsEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        set: u64,
+        clear: u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapSetTrackingMode(hConnection: LEAP_CONNECTION, mode: eLeapTrackingMode) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapSetTrackingModeEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        mode: eLeapTrackingMode,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetTrackingMode(hConnection: LEAP_CONNECTION) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetTrackingModeEx(hConnection: LEAP_CONNECTION, hDevice: LEAP_DEVICE) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetDeviceList(
+        hConnection: LEAP_CONNECTION,
+        pArray: *mut LEAP_DEVICE_REF,
+        pnArray: *mut u32,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapOpenDevice(rDevice: LEAP_DEVICE_REF, phDevice: *mut LEAP_DEVICE) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapSubscribeEvents(hConnection: LEAP_CONNECTION, hDevice: LEAP_DEVICE) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapUnsubscribeEvents(hConnection: LEAP_CONNECTION, hDevice: LEAP_DEVICE) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapSetPause(hConnection: LEAP_CONNECTION, pause: bool) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapSetPrimaryDevice(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        unsubscribeOthers: bool,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetDeviceInfo(hDevice: LEAP_DEVICE, info: *mut LEAP_DEVICE_INFO) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetDeviceTransformAvailable(hDevice: LEAP_DEVICE) -> bool;
+}
+extern "C" {
+    pub fn LeapGetDeviceTransform(hDevice: LEAP_DEVICE, transform: *mut f32) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetFrameSize(
+        hConnection: LEAP_CONNECTION,
+        timestamp: i64,
+        pncbEvent: *mut u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapGetFrameSizeEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        timestamp: i64,
+        pncbEvent: *mut u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateFrame(
+        hConnection: LEAP_CONNECTION,
+        timestamp: i64,
+        pEvent: *mut LEAP_TRACKING_EVENT,
+        ncbEvent: u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateFrameEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        timestamp: i64,
+        pEvent: *mut LEAP_TRACKING_EVENT,
+        ncbEvent: u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateFrameFromTime(
+        hConnection: LEAP_CONNECTION,
+        timestamp: i64,
+        sourceTimestamp: i64,
+        pEvent: *mut LEAP_TRACKING_EVENT,
+        ncbEvent: u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateFrameFromTimeEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        timestamp: i64,
+        sourceTimestamp: i64,
+        pEvent: *mut LEAP_TRACKING_EVENT,
+        ncbEvent: u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateHeadPose(
+        hConnection: LEAP_CONNECTION,
+        timestamp: i64,
+        pEvent: *mut LEAP_HEAD_POSE_EVENT,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateHeadPoseEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        timestamp: i64,
+        pEvent: *mut LEAP_HEAD_POSE_EVENT,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapInterpolateEyePositions(
+        hConnection: LEAP_CONNECTION,
+        timestamp: i64,
+        pEvent: *mut LEAP_EYE_EVENT,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapCloseDevice(hDevice: LEAP_DEVICE);
+}
+extern "C" {
+    pub fn LeapCloseConnection(hConnection: LEAP_CONNECTION);
+}
+extern "C" {
+    pub fn LeapDestroyConnection(hConnection: LEAP_CONNECTION);
+}
+
+pub const _eLeapPerspectiveType_eLeapPerspectiveType_invalid: _eLeapPerspectiveType = 0;
+pub const _eLeapPerspectiveType_eLeapPerspectiveType_stereo_left: _eLeapPerspectiveType = 1;
+pub const _eLeapPerspectiveType_eLeapPerspectiveType_stereo_right: _eLeapPerspectiveType = 2;
+pub const _eLeapPerspectiveType_eLeapPerspectiveType_mono: _eLeapPerspectiveType = 3;
+pub type _eLeapPerspectiveType = ::std::os::raw::c_uint;
+pub use self::_eLeapPerspectiveType as eLeapPerspectiveType;
+
+extern "C" {
+    pub fn LeapPixelToRectilinear(
+        hConnection: LEAP_CONNECTION,
+        camera: eLeapPerspectiveType,
+        pixel: LEAP_VECTOR,
+    ) -> LEAP_VECTOR;
+}
+extern "C" {
+    pub fn LeapPixelToRectilinearEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        camera: eLeapPerspectiveType,
+        pixel: LEAP_VECTOR,
+    ) -> LEAP_VECTOR;
+}
+extern "C" {
+    pub fn LeapRectilinearToPixel(
+        hConnection: LEAP_CONNECTION,
+        camera: eLeapPerspectiveType,
+        rectilinear: LEAP_VECTOR,
+    ) -> LEAP_VECTOR;
+}
+extern "C" {
+    pub fn LeapRectilinearToPixelEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        camera: eLeapPerspectiveType,
+        rectilinear: LEAP_VECTOR,
+    ) -> LEAP_VECTOR;
+}
+extern "C" {
+    pub fn LeapCameraMatrix(
+        hConnection: LEAP_CONNECTION,
+        camera: eLeapPerspectiveType,
+        dest: *mut f32,
+    );
+}
+extern "C" {
+    pub fn LeapCameraMatrixEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        camera: eLeapPerspectiveType,
+        dest: *mut f32,
+    );
+}
+extern "C" {
+    pub fn LeapExtrinsicCameraMatrix(
+        hConnection: LEAP_CONNECTION,
+        camera: eLeapPerspectiveType,
+        dest: *mut f32,
+    );
+}
+extern "C" {
+    pub fn LeapExtrinsicCameraMatrixEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        camera: eLeapPerspectiveType,
+        dest: *mut f32,
+    );
+}
+extern "C" {
+    pub fn LeapDistortionCoeffs(
+        hConnection: LEAP_CONNECTION,
+        camera: eLeapPerspectiveType,
+        dest: *mut f32,
+    );
+}
+extern "C" {
+    pub fn LeapDistortionCoeffsEx(
+        hConnection: LEAP_CONNECTION,
+        hDevice: LEAP_DEVICE,
+        camera: eLeapPerspectiveType,
+        dest: *mut f32,
+    );
+}
+
+pub const _eLeapVersionPart_eLeapVersionPart_ClientLibrary: _eLeapVersionPart = 0;
+pub const _eLeapVersionPart_eLeapVersionPart_ClientProtocol: _eLeapVersionPart = 1;
+pub const _eLeapVersionPart_eLeapVersionPart_ServerLibrary: _eLeapVersionPart = 2;
+pub const _eLeapVersionPart_eLeapVersionPart_ServerProtocol: _eLeapVersionPart = 3;
+pub type _eLeapVersionPart = ::std::os::raw::c_uint;
+pub use self::_eLeapVersionPart as eLeapVersionPart;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_VERSION {
+    pub major: i32,
+    pub minor: i32,
+    pub patch: i32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_VERSION() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_VERSION> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_VERSION>(),
+        12usize,
+        concat!("Size of: ", stringify!(_LEAP_VERSION))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_VERSION>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_VERSION))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).major) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VERSION),
+            "::",
+            stringify!(major)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).minor) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VERSION),
+            "::",
+            stringify!(minor)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).patch) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_VERSION),
+            "::",
+            stringify!(patch)
+        )
+    );
+}
+pub type LEAP_VERSION = _LEAP_VERSION;
+
+extern "C" {
+    pub fn LeapGetVersion(
+        hConnection: LEAP_CONNECTION,
+        versionPart: eLeapVersionPart,
+        pVersion: *mut LEAP_VERSION,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapDevicePIDToString(pid: eLeapDevicePID) -> *const ::std::os::raw::c_char;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_RECORDING {
+    _unused: [u8; 0],
+}
+pub type LEAP_RECORDING = *mut _LEAP_RECORDING;
+
+pub const _eLeapRecordingFlags_eLeapRecordingFlags_Error: _eLeapRecordingFlags = 0;
+pub const _eLeapRecordingFlags_eLeapRecordingFlags_Reading: _eLeapRecordingFlags = 1;
+pub const _eLeapRecordingFlags_eLeapRecordingFlags_Writing: _eLeapRecordingFlags = 2;
+pub const _eLeapRecordingFlags_eLeapRecordingFlags_Flush: _eLeapRecordingFlags = 4;
+pub const _eLeapRecordingFlags_eLeapRecordingFlags_Compressed: _eLeapRecordingFlags = 8;
+pub type _eLeapRecordingFlags = ::std::os::raw::c_uint;
+pub use self::_eLeapRecordingFlags as eLeapRecordingFlags;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_RECORDING_PARAMETERS {
+    pub mode: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_RECORDING_PARAMETERS() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_RECORDING_PARAMETERS> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_RECORDING_PARAMETERS>(),
+        4usize,
+        concat!("Size of: ", stringify!(_LEAP_RECORDING_PARAMETERS))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_RECORDING_PARAMETERS>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_RECORDING_PARAMETERS))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mode) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_RECORDING_PARAMETERS),
+            "::",
+            stringify!(mode)
+        )
+    );
+}
+pub type LEAP_RECORDING_PARAMETERS = _LEAP_RECORDING_PARAMETERS;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_RECORDING_STATUS {
+    pub mode: u32,
+}
+#[test]
+fn bindgen_test_layout__LEAP_RECORDING_STATUS() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_RECORDING_STATUS> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_RECORDING_STATUS>(),
+        4usize,
+        concat!("Size of: ", stringify!(_LEAP_RECORDING_STATUS))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_RECORDING_STATUS>(),
+        4usize,
+        concat!("Alignment of ", stringify!(_LEAP_RECORDING_STATUS))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mode) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_RECORDING_STATUS),
+            "::",
+            stringify!(mode)
+        )
+    );
+}
+pub type LEAP_RECORDING_STATUS = _LEAP_RECORDING_STATUS;
+
+extern "C" {
+    pub fn LeapRecordingOpen(
+        ppRecording: *mut LEAP_RECORDING,
+        filePath: *const ::std::os::raw::c_char,
+        params: LEAP_RECORDING_PARAMETERS,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRecordingClose(ppRecording: *mut LEAP_RECORDING) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRecordingGetStatus(
+        pRecording: LEAP_RECORDING,
+        pstatus: *mut LEAP_RECORDING_STATUS,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRecordingReadSize(pRecording: LEAP_RECORDING, pncbEvent: *mut u64) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRecordingRead(
+        pRecording: LEAP_RECORDING,
+        pEvent: *mut LEAP_TRACKING_EVENT,
+        ncbEvent: u64,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapRecordingWrite(
+        pRecording: LEAP_RECORDING,
+        pEvent: *mut LEAP_TRACKING_EVENT,
+        pnBytesWritten: *mut u64,
+    ) -> eLeapRS;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_TELEMETRY_DATA {
+    pub thread_id: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub zone_depth: u32,
+    pub file_name: *const ::std::os::raw::c_char,
+    pub line_number: u32,
+    pub zone_name: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__LEAP_TELEMETRY_DATA() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_TELEMETRY_DATA> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_TELEMETRY_DATA>(),
+        56usize,
+        concat!("Size of: ", stringify!(_LEAP_TELEMETRY_DATA))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_TELEMETRY_DATA>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_TELEMETRY_DATA))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thread_id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(thread_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).start_time) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(start_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).end_time) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(end_time)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).zone_depth) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(zone_depth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).file_name) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(file_name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).line_number) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(line_number)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).zone_name) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_TELEMETRY_DATA),
+            "::",
+            stringify!(zone_name)
+        )
+    );
+}
+pub type LEAP_TELEMETRY_DATA = _LEAP_TELEMETRY_DATA;
+
+extern "C" {
+    pub fn LeapTelemetryProfiling(
+        hConnection: LEAP_CONNECTION,
+        telemetryData: *const LEAP_TELEMETRY_DATA,
+    ) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapTelemetryGetNow() -> u64;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_SERVER_STATUS_DEVICE {
+    pub serial: *const ::std::os::raw::c_char,
+    pub type_: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__LEAP_SERVER_STATUS_DEVICE() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_SERVER_STATUS_DEVICE> =
+        ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_SERVER_STATUS_DEVICE>(),
+        16usize,
+        concat!("Size of: ", stringify!(_LEAP_SERVER_STATUS_DEVICE))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_SERVER_STATUS_DEVICE>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_SERVER_STATUS_DEVICE))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).serial) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_SERVER_STATUS_DEVICE),
+            "::",
+            stringify!(serial)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_SERVER_STATUS_DEVICE),
+            "::",
+            stringify!(type_)
+        )
+    );
+}
+pub type LEAP_SERVER_STATUS_DEVICE = _LEAP_SERVER_STATUS_DEVICE;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LEAP_SERVER_STATUS {
+    pub version: *const ::std::os::raw::c_char,
+    pub device_count: u32,
+    pub devices: *const LEAP_SERVER_STATUS_DEVICE,
+}
+#[test]
+fn bindgen_test_layout__LEAP_SERVER_STATUS() {
+    const UNINIT: ::std::mem::MaybeUninit<_LEAP_SERVER_STATUS> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_LEAP_SERVER_STATUS>(),
+        24usize,
+        concat!("Size of: ", stringify!(_LEAP_SERVER_STATUS))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_LEAP_SERVER_STATUS>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_LEAP_SERVER_STATUS))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).version) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_SERVER_STATUS),
+            "::",
+            stringify!(version)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).device_count) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_SERVER_STATUS),
+            "::",
+            stringify!(device_count)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).devices) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_LEAP_SERVER_STATUS),
+            "::",
+            stringify!(devices)
+        )
+    );
+}
+pub type LEAP_SERVER_STATUS = _LEAP_SERVER_STATUS;
+
+extern "C" {
+    pub fn LeapGetServerStatus(timeout: u32, status: *mut *const LEAP_SERVER_STATUS) -> eLeapRS;
+}
+extern "C" {
+    pub fn LeapReleaseServerStatus(status: *const LEAP_SERVER_STATUS) -> eLeapRS;
+}
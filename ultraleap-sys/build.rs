@@ -0,0 +1,144 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Locations of the LeapC header and library.
+struct LeapSdk {
+    // only needed to generate the bindings
+    #[cfg_attr(not(feature = "bindgen"), allow(dead_code))]
+    include_dir: PathBuf,
+    lib_dir: PathBuf,
+}
+
+impl LeapSdk {
+    /// SDK directory with the usual `include` and `lib` layout.
+    fn in_dir(leap_sdk_dir: &Path) -> LeapSdk {
+        let mut lib_dir = leap_sdk_dir.join("lib");
+        if env::consts::OS == "windows" {
+            lib_dir = lib_dir.join("x64");
+        }
+        LeapSdk {
+            include_dir: leap_sdk_dir.join("include"),
+            lib_dir,
+        }
+    }
+
+    /// `LEAPSDK_DIR` if set, otherwise where the tracking software installs the SDK.
+    fn find() -> LeapSdk {
+        if let Some(leap_sdk_dir) = env::var_os("LEAPSDK_DIR") {
+            return Self::in_dir(Path::new(&leap_sdk_dir));
+        }
+        match env::consts::OS {
+            "macos" => Self::in_dir(Path::new(
+                "/Applications/Ultraleap Hand Tracking.app/Contents/LeapSDK",
+            )),
+            "windows" => Self::in_dir(Path::new("C:\\Program Files\\Ultraleap\\LeapSDK")),
+            // the Linux packages split the SDK between the service and the system headers
+            _ => LeapSdk {
+                include_dir: PathBuf::from("/usr/include"),
+                lib_dir: PathBuf::from("/usr/lib/ultraleap-hand-tracking-service"),
+            },
+        }
+    }
+}
+
+fn main() {
+    // with the `dynamic-loading` feature LeapC is loaded at runtime, not linked
+    let dynamic_loading = env::var_os("CARGO_FEATURE_DYNAMIC_LOADING").is_some();
+    println!("cargo:rerun-if-env-changed=LEAPSDK_DIR");
+
+    // Write the bindings to the $OUT_DIR/bindings.rs file.
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
+    write_bindings(dynamic_loading, &out_path);
+
+    // nothing else of the SDK is needed when LeapC is loaded at runtime
+    if dynamic_loading {
+        return;
+    }
+    let leap_sdk = LeapSdk::find();
+
+    // Canonicalize the path as `rustc-link-search` requires an absolute
+    // path.
+    match leap_sdk.lib_dir.canonicalize() {
+        // Tell cargo to look for shared libraries in the specified directory
+        Ok(lib_dir) => println!("cargo:rustc-link-search={}", lib_dir.display()),
+        Err(_) => println!(
+            "cargo:warning=LeapC library directory {} not found, set LEAPSDK_DIR or enable the `dynamic-loading` feature",
+            leap_sdk.lib_dir.display()
+        ),
+    }
+
+    // Tell cargo to tell rustc to link the system LeapC
+    // shared library.
+    println!("cargo:rustc-link-lib=LeapC");
+
+    if env::consts::OS == "windows" {
+        // on windows copy to the target directory
+        let src = leap_sdk.lib_dir.join("LeapC.dll");
+        // let bin_name = env::var("CARGO_BIN_NAME").unwrap();
+        // let bin_path_env = format!("CARGO_TARGET_DIR_{}", bin_name);
+        let manifest_dir_string = env::var("CARGO_MANIFEST_DIR").unwrap();
+        let build_type = env::var("PROFILE").unwrap();
+        let dst = Path::new(&manifest_dir_string)
+            .parent()
+            .unwrap()
+            .join("target")
+            .join(build_type)
+            .join("LeapC.dll");
+        // let mut dst = PathBuf::from(env::var(bin_path_env).unwrap());
+        // let dst = PathBuf::from(path);
+        println!("cargo:warning={:#?}", dst);
+        std::fs::copy(src, dst).expect("Failed to copy LeapC.dll");
+    }
+}
+
+/// Generates the bindings from the SDK header, taking precedence over the
+/// prebuilt ones so they can be regenerated.
+#[cfg(feature = "bindgen")]
+fn write_bindings(dynamic_loading: bool, out_path: &Path) {
+    // This is the path to the `c` headers file.
+    let headers_path = LeapSdk::find().include_dir.join("LeapC.h");
+    let headers_path_str = headers_path.to_str().expect("Path is not a valid string");
+
+    // Tell cargo to invalidate the built crate whenever the wrapper changes
+    println!("cargo:rerun-if-changed={}", headers_path_str);
+
+    // The bindgen::Builder is the main entry point
+    // to bindgen, and lets you build up options for
+    // the resulting bindings.
+    let mut builder = bindgen::Builder::default()
+        // The input header we would like to generate
+        // bindings for.
+        .header(headers_path_str)
+        // Tell cargo to invalidate the built crate whenever any of the
+        // included header files changed.
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks));
+    if dynamic_loading {
+        // the functions are looked up in the loaded library instead
+        builder = builder.blocklist_function("Leap.*");
+    }
+    let bindings = builder
+        // Finish the builder and generate the bindings.
+        .generate()
+        // Unwrap the Result and panic on failure.
+        .expect("Unable to generate bindings");
+
+    bindings
+        .write_to_file(out_path)
+        .expect("Couldn't write bindings!");
+}
+
+#[cfg(all(not(feature = "bindgen"), feature = "prebuilt-bindings"))]
+fn write_bindings(_dynamic_loading: bool, out_path: &Path) {
+    /// Checked in bindings, versioned by the LeapC major version.
+    const PREBUILT_BINDINGS: &str = "bindings/leapc_5.rs";
+
+    // the declared functions are only linked when they are called, which the
+    // `dynamic-loading` feature never does
+    println!("cargo:rerun-if-changed={}", PREBUILT_BINDINGS);
+    std::fs::copy(PREBUILT_BINDINGS, out_path).expect("Couldn't copy prebuilt bindings!");
+}
+
+#[cfg(not(any(feature = "bindgen", feature = "prebuilt-bindings")))]
+fn write_bindings(_dynamic_loading: bool, _out_path: &Path) {
+    panic!("enable the `prebuilt-bindings` or the `bindgen` feature");
+}
@@ -0,0 +1,6 @@
+//! Raw bindings to LeapC, the C API of the Ultraleap hand tracking service.
+//! The `ultraleap` crate wraps them in a safe API.
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
@@ -0,0 +1,30 @@
+use crate::{
+    _eLeapConnectionStatus_eLeapConnectionStatus_Connected,
+    _eLeapConnectionStatus_eLeapConnectionStatus_HandshakeIncomplete, eLeapConnectionStatus,
+};
+
+/// State of the connection between a controller and the tracking service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionStatus {
+    /// Connected to the service and receiving events.
+    Connected,
+    /// No connection, usually because the service is not running.
+    NotConnected,
+    /// The connection is open but the handshake with the service has not finished.
+    HandshakeIncomplete,
+    /// The service went away after a connection had been established.
+    Lost,
+}
+
+impl ConnectionStatus {
+    pub(crate) fn from_raw(raw_status: eLeapConnectionStatus) -> ConnectionStatus {
+        match raw_status {
+            _eLeapConnectionStatus_eLeapConnectionStatus_Connected => ConnectionStatus::Connected,
+            _eLeapConnectionStatus_eLeapConnectionStatus_HandshakeIncomplete => {
+                ConnectionStatus::HandshakeIncomplete
+            }
+            // not connected and service not running
+            _ => ConnectionStatus::NotConnected,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::DeliveryPolicy;
+use std::time::Duration;
+
+/// Options for opening a [`crate::LeapController`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerConfig {
+    /// Opens the connection with `eLeapConnectionConfig_MultiDeviceAware`,
+    /// tracking events of a device are then only received after subscribing
+    /// to it with [`crate::LeapController::subscribe_device`].
+    pub multi_device_aware: bool,
+    /// How pending tracking frames are kept.
+    pub delivery_policy: DeliveryPolicy,
+    /// How long [`crate::LeapController::try_with_config`] waits for the
+    /// service to accept the connection, one second by default. `None` only
+    /// reports failures to set up the connection locally.
+    pub connection_timeout: Option<Duration>,
+}
+
+impl Default for ControllerConfig {
+    fn default() -> Self {
+        ControllerConfig {
+            multi_device_aware: false,
+            delivery_policy: DeliveryPolicy::default(),
+            connection_timeout: Some(Duration::from_secs(1)),
+        }
+    }
+}
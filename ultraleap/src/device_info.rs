@@ -0,0 +1,99 @@
+use crate::{leap_error::leap_result, leap_event::string_from_raw, leapc::LeapGetDeviceInfo, *};
+use std::mem::{size_of, MaybeUninit};
+use std::os::raw::c_char;
+
+/// Properties of an attached tracking device.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceInfo {
+    pub id: u32,
+    pub serial: String,
+    /// `eLeapDevicePID` product id, see [`DeviceInfo::model`].
+    pub pid: u32,
+    /// Horizontal field of view in radians.
+    pub h_fov: f32,
+    /// Vertical field of view in radians.
+    pub v_fov: f32,
+    /// Maximum tracking range in micrometers.
+    pub range: u32,
+    /// Distance between the stereo cameras in micrometers.
+    pub baseline: u32,
+    /// `eLeapDeviceCaps` flags.
+    pub caps: u32,
+    /// `eLeapDeviceStatus` flags.
+    pub status: u32,
+}
+
+impl DeviceInfo {
+    /// Converts the info `LeapGetDeviceInfo` filled in through the `raw` API
+    /// for the device with `id`.
+    ///
+    /// # Safety
+    ///
+    /// `serial` must be null or point to a nul terminated string.
+    #[cfg(feature = "raw")]
+    pub unsafe fn from_leapc(id: u32, raw_device_info: &_LEAP_DEVICE_INFO) -> DeviceInfo {
+        DeviceInfo::from_raw(id, raw_device_info)
+    }
+
+    pub(crate) fn from_raw(id: u32, raw_device_info: &_LEAP_DEVICE_INFO) -> DeviceInfo {
+        DeviceInfo {
+            id,
+            serial: unsafe { string_from_raw(raw_device_info.serial) },
+            pid: raw_device_info.pid as u32,
+            h_fov: raw_device_info.h_fov,
+            v_fov: raw_device_info.v_fov,
+            range: raw_device_info.range,
+            baseline: raw_device_info.baseline,
+            caps: raw_device_info.caps,
+            status: raw_device_info.status,
+        }
+    }
+
+    /// Reads the info of an opened device.
+    pub(crate) unsafe fn from_device(
+        id: u32,
+        leap_device: LEAP_DEVICE,
+    ) -> Result<DeviceInfo, LeapError> {
+        const SERIAL_SIZE: usize = 1000;
+        let mut serial: [c_char; SERIAL_SIZE] = [0; SERIAL_SIZE];
+        let mut leap_device_info: MaybeUninit<LEAP_DEVICE_INFO> = MaybeUninit::zeroed();
+        (*leap_device_info.as_mut_ptr()).serial_length = (SERIAL_SIZE - 1) as u32;
+        (*leap_device_info.as_mut_ptr()).serial = serial.as_mut_ptr();
+        (*leap_device_info.as_mut_ptr()).size = size_of::<LEAP_DEVICE_INFO>() as u32;
+        leap_result(LeapGetDeviceInfo(
+            leap_device,
+            leap_device_info.as_mut_ptr(),
+        ))?;
+        Ok(DeviceInfo::from_raw(id, &leap_device_info.assume_init()))
+    }
+
+    /// Human readable name of the device model.
+    pub fn model(&self) -> &'static str {
+        match self.pid as eLeapDevicePID {
+            _eLeapDevicePID_eLeapDevicePID_Peripheral => "Leap Motion Controller",
+            _eLeapDevicePID_eLeapDevicePID_Dragonfly => "Dragonfly",
+            _eLeapDevicePID_eLeapDevicePID_Nightcrawler => "Nightcrawler",
+            _eLeapDevicePID_eLeapDevicePID_Rigel => "Rigel",
+            _eLeapDevicePID_eLeapDevicePID_SIR170 => "Stereo IR 170",
+            _eLeapDevicePID_eLeapDevicePID_3Di => "3Di",
+            _eLeapDevicePID_eLeapDevicePID_LMC2 => "Leap Motion Controller 2",
+            _ => "Unknown",
+        }
+    }
+
+    pub fn is_streaming(&self) -> bool {
+        self.has_status(_eLeapDeviceStatus_eLeapDeviceStatus_Streaming)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.has_status(_eLeapDeviceStatus_eLeapDeviceStatus_Paused)
+    }
+
+    pub fn is_smudged(&self) -> bool {
+        self.has_status(_eLeapDeviceStatus_eLeapDeviceStatus_Smudged)
+    }
+
+    fn has_status(&self, flag: eLeapDeviceStatus) -> bool {
+        self.status & flag as u32 != 0
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{
+    event_queue::*, event_receiver::*, subscription::*, tracking_event::TrackingEvent, DeviceInfo,
+    PolicyFlags, TrackingMode,
+};
+use std::sync::Arc;
+
+/// Consumer side shared by the sources: the events handed to the source's
+/// own receiver and any further ones, and the callbacks registered on it.
+pub(crate) struct EventHub {
+    broadcast: Arc<EventBroadcast>,
+    receiver: EventReceiver,
+    callbacks: Arc<Callbacks>,
+}
+
+impl EventHub {
+    pub fn new() -> EventHub {
+        let broadcast = Arc::new(EventBroadcast::new());
+        let receiver = EventReceiver::new(&broadcast, DeliveryPolicy::default());
+        EventHub {
+            broadcast,
+            receiver,
+            callbacks: Arc::new(Callbacks::new()),
+        }
+    }
+
+    /// Where the thread producing the events pushes them.
+    pub fn broadcast(&self) -> &Arc<EventBroadcast> {
+        &self.broadcast
+    }
+
+    /// The callbacks the thread producing the events invokes.
+    pub fn callbacks(&self) -> &Arc<Callbacks> {
+        &self.callbacks
+    }
+
+    pub fn receiver(&self) -> &EventReceiver {
+        &self.receiver
+    }
+
+    pub fn subscribe(&self, policy: DeliveryPolicy) -> EventReceiver {
+        EventReceiver::new(&self.broadcast, policy)
+    }
+
+    pub fn on_tracking(&self, callback: Box<dyn FnMut(&TrackingEvent) + Send>) -> Subscription {
+        Callbacks::subscribe(&self.callbacks, |callbacks| &callbacks.tracking, callback)
+    }
+
+    pub fn on_device_attached(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        Callbacks::subscribe(
+            &self.callbacks,
+            |callbacks| &callbacks.device_attached,
+            callback,
+        )
+    }
+
+    pub fn on_device_lost(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        Callbacks::subscribe(
+            &self.callbacks,
+            |callbacks| &callbacks.device_lost,
+            callback,
+        )
+    }
+
+    pub fn on_connection_lost(&self, mut callback: Box<dyn FnMut() + Send>) -> Subscription {
+        Callbacks::subscribe(
+            &self.callbacks,
+            |callbacks| &callbacks.connection_lost,
+            Box::new(move |_| callback()),
+        )
+    }
+
+    pub fn on_policy_changed(&self, callback: Box<dyn FnMut(&PolicyFlags) + Send>) -> Subscription {
+        Callbacks::subscribe(
+            &self.callbacks,
+            |callbacks| &callbacks.policy_changed,
+            callback,
+        )
+    }
+
+    pub fn on_tracking_mode_changed(
+        &self,
+        callback: Box<dyn FnMut(&TrackingMode) + Send>,
+    ) -> Subscription {
+        Callbacks::subscribe(
+            &self.callbacks,
+            |callbacks| &callbacks.tracking_mode_changed,
+            callback,
+        )
+    }
+}
@@ -0,0 +1,341 @@
+use crate::{tracking_event::TrackingEvent, LeapEvent};
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::task::Waker;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+/// How tracking frames are kept until they are taken from a controller,
+/// all other events are always queued.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeliveryPolicy {
+    /// Only the newest frame is kept, a new frame overwrites the pending one.
+    LatestOnly,
+    /// Up to the given number of frames are kept, the oldest is dropped when full.
+    Bounded(usize),
+    /// Every frame is kept until it is taken.
+    #[default]
+    Unbounded,
+}
+
+impl DeliveryPolicy {
+    fn max_frames(&self) -> Option<usize> {
+        match self {
+            DeliveryPolicy::LatestOnly => Some(1),
+            DeliveryPolicy::Bounded(max_frames) => Some((*max_frames).max(1)),
+            DeliveryPolicy::Unbounded => None,
+        }
+    }
+}
+
+struct QueueState {
+    /// The events with the time they were pushed.
+    events: VecDeque<(Instant, LeapEvent)>,
+    policy: DeliveryPolicy,
+    frames: usize,
+    closed: bool,
+    wakers: Vec<Waker>,
+}
+
+impl QueueState {
+    fn pop_front(&mut self) -> Option<(Instant, LeapEvent)> {
+        let event = self.events.pop_front();
+        if let Some((_, LeapEvent::Tracking(_))) = event {
+            self.frames -= 1;
+        }
+        event
+    }
+
+    fn drop_excess_frames(&mut self) {
+        let max_frames = match self.policy.max_frames() {
+            Some(max_frames) => max_frames,
+            _ => return,
+        };
+        while self.frames > max_frames {
+            let oldest_frame = self
+                .events
+                .iter()
+                .position(|(_, event)| matches!(event, LeapEvent::Tracking(_)))
+                .unwrap();
+            self.events.remove(oldest_frame);
+            self.frames -= 1;
+        }
+    }
+}
+
+/// Queue between the polling thread and one receiver of a controller.
+pub(crate) struct EventQueue {
+    state: Mutex<QueueState>,
+    available: Condvar,
+}
+
+impl EventQueue {
+    pub fn new(policy: DeliveryPolicy) -> EventQueue {
+        EventQueue {
+            state: Mutex::new(QueueState {
+                events: VecDeque::new(),
+                policy,
+                frames: 0,
+                closed: false,
+                wakers: vec![],
+            }),
+            available: Condvar::new(),
+        }
+    }
+
+    pub fn set_policy(&self, policy: DeliveryPolicy) {
+        let mut state = self.state.lock().unwrap();
+        state.policy = policy;
+        state.drop_excess_frames();
+    }
+
+    pub fn push(&self, event: LeapEvent) {
+        let mut state = self.state.lock().unwrap();
+        if let LeapEvent::Tracking(_) = event {
+            state.frames += 1;
+        }
+        state.events.push_back((Instant::now(), event));
+        state.drop_excess_frames();
+        self.wake(state);
+    }
+
+    /// Marks the end of the events, waiting consumers return once the queue is empty.
+    pub fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        self.wake(state);
+    }
+
+    /// Wakes blocked and async consumers once the lock is released.
+    fn wake(&self, mut state: MutexGuard<QueueState>) {
+        let wakers: Vec<Waker> = state.wakers.drain(..).collect();
+        drop(state);
+        self.available.notify_all();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Closed and all events taken.
+    pub fn is_finished(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.closed && state.events.is_empty()
+    }
+
+    pub fn pop(&self) -> Option<LeapEvent> {
+        self.state
+            .lock()
+            .unwrap()
+            .pop_front()
+            .map(|(_, event)| event)
+    }
+
+    /// Like [`EventQueue::pop`] but blocks until an event arrives, the deadline
+    /// passes or the queue is closed.
+    pub fn wait_pop(&self, deadline: Option<Instant>) -> Option<LeapEvent> {
+        self.wait_pop_timed(deadline).map(|(_, event)| event)
+    }
+
+    /// Like [`EventQueue::wait_pop`], also returning when the event was pushed.
+    pub fn wait_pop_timed(&self, deadline: Option<Instant>) -> Option<(Instant, LeapEvent)> {
+        let mut state = self.state.lock().unwrap();
+        while state.events.is_empty() && !state.closed {
+            state = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    self.available
+                        .wait_timeout(state, deadline - now)
+                        .unwrap()
+                        .0
+                }
+                None => self.available.wait(state).unwrap(),
+            };
+        }
+        state.pop_front()
+    }
+
+    /// Like [`EventQueue::wait_pop`] for async consumers, the waker is woken
+    /// by the polling thread.
+    #[cfg(feature = "async")]
+    pub fn poll_pop(&self, cx: &mut Context<'_>) -> Poll<Option<LeapEvent>> {
+        let mut state = self.state.lock().unwrap();
+        if state.events.is_empty() && !state.closed {
+            if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                state.wakers.push(cx.waker().clone());
+            }
+            return Poll::Pending;
+        }
+        Poll::Ready(state.pop_front().map(|(_, event)| event))
+    }
+
+    /// Empties the queue, returning only the tracking frames.
+    pub fn drain_frames(&self) -> Vec<Arc<TrackingEvent>> {
+        let mut state = self.state.lock().unwrap();
+        state.frames = 0;
+        state
+            .events
+            .drain(..)
+            .filter_map(|(_, event)| match event {
+                LeapEvent::Tracking(tracking_event) => Some(tracking_event),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+struct BroadcastState {
+    queues: Vec<Weak<EventQueue>>,
+    closed: bool,
+}
+
+/// Hands every event of the polling thread to the queues of all receivers.
+pub(crate) struct EventBroadcast {
+    state: Mutex<BroadcastState>,
+}
+
+impl EventBroadcast {
+    pub fn new() -> EventBroadcast {
+        EventBroadcast {
+            state: Mutex::new(BroadcastState {
+                queues: vec![],
+                closed: false,
+            }),
+        }
+    }
+
+    /// Adds a receiver queue, it only gets the events pushed from now on.
+    pub fn add(&self, queue: &Arc<EventQueue>) {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            queue.close();
+        }
+        state.queues.push(Arc::downgrade(queue));
+    }
+
+    /// Pushes the event to every live queue, forgetting the dropped ones.
+    pub fn push(&self, event: LeapEvent) {
+        let mut state = self.state.lock().unwrap();
+        state.queues.retain(|queue| match queue.upgrade() {
+            Some(queue) => {
+                queue.push(event.clone());
+                true
+            }
+            None => false,
+        });
+    }
+
+    pub fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        for queue in state.queues.iter().filter_map(Weak::upgrade) {
+            queue.close();
+        }
+    }
+}
+
+/// Closes the broadcast when dropped, so the receivers also end when the
+/// thread producing the events unwinds from a panicking callback.
+pub(crate) struct CloseOnDrop<'a>(pub &'a EventBroadcast);
+
+impl Drop for CloseOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EventReceiver, HandAnimation, Handedness};
+
+    fn frames(count: usize) -> Vec<LeapEvent> {
+        let frames = HandAnimation::open_fist_pinch(Handedness::Right).frames(10.0);
+        frames
+            .into_iter()
+            .take(count)
+            .map(|frame| LeapEvent::Tracking(Arc::new(frame)))
+            .collect()
+    }
+
+    fn event_ids(events: impl IntoIterator<Item = LeapEvent>) -> Vec<Option<i64>> {
+        events
+            .into_iter()
+            .map(|event| match event {
+                LeapEvent::Tracking(tracking_event) => Some(tracking_event.event_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn pop_all(event_queue: &EventQueue) -> Vec<LeapEvent> {
+        std::iter::from_fn(|| event_queue.pop()).collect()
+    }
+
+    #[test]
+    fn latest_only_overwrites_the_pending_frame() {
+        let event_queue = EventQueue::new(DeliveryPolicy::LatestOnly);
+        for event in frames(3) {
+            event_queue.push(event);
+        }
+        assert_eq!(event_ids(pop_all(&event_queue)), [Some(2)]);
+    }
+
+    #[test]
+    fn bounded_drops_the_oldest_frames_and_keeps_other_events() {
+        let event_queue = EventQueue::new(DeliveryPolicy::Bounded(2));
+        for event in frames(4) {
+            event_queue.push(event);
+            event_queue.push(LeapEvent::ConnectionLost);
+        }
+        assert_eq!(
+            event_ids(pop_all(&event_queue)),
+            [None, None, Some(2), None, Some(3), None]
+        );
+    }
+
+    #[test]
+    fn drain_takes_only_the_frames() {
+        let event_queue = EventQueue::new(DeliveryPolicy::Unbounded);
+        for event in frames(3) {
+            event_queue.push(LeapEvent::ConnectionLost);
+            event_queue.push(event);
+        }
+        let drained: Vec<i64> = event_queue
+            .drain_frames()
+            .iter()
+            .map(|tracking_event| tracking_event.event_id)
+            .collect();
+        assert_eq!(drained, [0, 1, 2]);
+        assert!(event_queue.pop().is_none());
+
+        // the frame count starts over
+        event_queue.set_policy(DeliveryPolicy::LatestOnly);
+        for event in frames(2) {
+            event_queue.push(event);
+        }
+        assert_eq!(event_ids(pop_all(&event_queue)), [Some(1)]);
+    }
+
+    #[test]
+    fn every_receiver_gets_every_frame() {
+        let broadcast = EventBroadcast::new();
+        let first = EventReceiver::new(&broadcast, DeliveryPolicy::Unbounded);
+        let second = EventReceiver::new(&broadcast, DeliveryPolicy::Unbounded);
+        for event in frames(3) {
+            broadcast.push(event);
+        }
+        broadcast.close();
+        for receiver in [first, second] {
+            let event_ids: Vec<i64> = receiver
+                .frames()
+                .map(|tracking_event| tracking_event.event_id)
+                .collect();
+            assert_eq!(event_ids, [0, 1, 2]);
+            assert!(receiver.is_finished());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(feature = "async")]
+use crate::event_stream::*;
+use crate::{event_queue::*, tracking_event::TrackingEvent, LeapEvent};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Independent consumer of the events of a controller, see
+/// [`LeapController::subscribe`](crate::LeapController::subscribe).
+///
+/// Every receiver gets all events from its creation on, with its own
+/// [`DeliveryPolicy`] for the pending tracking frames.
+pub struct EventReceiver {
+    event_queue: Arc<EventQueue>,
+}
+
+impl EventReceiver {
+    pub(crate) fn new(broadcast: &EventBroadcast, policy: DeliveryPolicy) -> EventReceiver {
+        let event_queue = Arc::new(EventQueue::new(policy));
+        broadcast.add(&event_queue);
+        EventReceiver { event_queue }
+    }
+
+    /// Changes how pending tracking frames are kept, see [`DeliveryPolicy`].
+    pub fn set_delivery_policy(&self, policy: DeliveryPolicy) {
+        self.event_queue.set_policy(policy);
+    }
+
+    /// Takes the next pending event of any type.
+    pub fn poll_event(&self) -> Option<LeapEvent> {
+        self.event_queue.pop()
+    }
+
+    /// Waits up to `timeout` for the next event of any type.
+    pub fn wait_event(&self, timeout: Duration) -> Option<LeapEvent> {
+        self.event_queue.wait_pop(Some(Instant::now() + timeout))
+    }
+
+    /// Like [`EventReceiver::wait_event`], also returning when the event was
+    /// handed out by the thread producing it.
+    pub(crate) fn wait_timed_event(&self, timeout: Duration) -> Option<(Instant, LeapEvent)> {
+        self.event_queue
+            .wait_pop_timed(Some(Instant::now() + timeout))
+    }
+
+    /// Whether the connection is closed and all of its events are taken, no
+    /// more events will arrive.
+    pub fn is_finished(&self) -> bool {
+        self.event_queue.is_finished()
+    }
+
+    /// Takes the next pending tracking event, skipping all other events.
+    pub fn get_tracking_event(&self) -> Option<Arc<TrackingEvent>> {
+        while let Some(event) = self.poll_event() {
+            if let LeapEvent::Tracking(tracking_event) = event {
+                return Some(tracking_event);
+            }
+        }
+        None
+    }
+
+    /// Waits up to `timeout` for the next tracking event, skipping all other events.
+    pub fn wait_tracking_event(&self, timeout: Duration) -> Option<Arc<TrackingEvent>> {
+        let deadline = Instant::now() + timeout;
+        while let Some(event) = self.event_queue.wait_pop(Some(deadline)) {
+            if let LeapEvent::Tracking(tracking_event) = event {
+                return Some(tracking_event);
+            }
+        }
+        None
+    }
+
+    /// Blocking iterator over the tracking events, it ends when the connection
+    /// to the service is lost or closed.
+    pub fn frames(&self) -> Frames<'_> {
+        Frames {
+            event_queue: &self.event_queue,
+        }
+    }
+
+    /// Stream of all events, sharing the pending events with
+    /// [`EventReceiver::poll_event`].
+    #[cfg(feature = "async")]
+    pub fn event_stream(&self) -> EventStream {
+        EventStream::new(self.event_queue.clone())
+    }
+
+    /// Stream of the tracking events, skipping all other events.
+    #[cfg(feature = "async")]
+    pub fn tracking_stream(&self) -> TrackingStream {
+        TrackingStream::new(self.event_queue.clone())
+    }
+
+    /// Takes all pending tracking events, oldest first, skipping all other events.
+    pub fn drain(&self) -> Vec<Arc<TrackingEvent>> {
+        self.event_queue.drain_frames()
+    }
+}
+
+pub struct Frames<'a> {
+    event_queue: &'a EventQueue,
+}
+
+impl Iterator for Frames<'_> {
+    type Item = Arc<TrackingEvent>;
+
+    fn next(&mut self) -> Option<Arc<TrackingEvent>> {
+        loop {
+            match self.event_queue.wait_pop(None)? {
+                LeapEvent::Tracking(tracking_event) => return Some(tracking_event),
+                LeapEvent::ConnectionLost => return None,
+                _ => continue,
+            }
+        }
+    }
+}
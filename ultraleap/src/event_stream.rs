@@ -0,0 +1,53 @@
+use crate::{event_queue::EventQueue, tracking_event::TrackingEvent, LeapEvent};
+use futures_core::Stream;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// Stream of all events of a receiver, it ends when the connection is closed.
+pub struct EventStream {
+    event_queue: Arc<EventQueue>,
+}
+
+impl EventStream {
+    pub(crate) fn new(event_queue: Arc<EventQueue>) -> EventStream {
+        EventStream { event_queue }
+    }
+}
+
+impl Stream for EventStream {
+    type Item = LeapEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<LeapEvent>> {
+        self.event_queue.poll_pop(cx)
+    }
+}
+
+/// Stream of the tracking events of a receiver, it ends when the connection
+/// is closed.
+pub struct TrackingStream {
+    event_queue: Arc<EventQueue>,
+}
+
+impl TrackingStream {
+    pub(crate) fn new(event_queue: Arc<EventQueue>) -> TrackingStream {
+        TrackingStream { event_queue }
+    }
+}
+
+impl Stream for TrackingStream {
+    type Item = Arc<TrackingEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Arc<TrackingEvent>>> {
+        loop {
+            match self.event_queue.poll_pop(cx) {
+                Poll::Ready(Some(LeapEvent::Tracking(tracking_event))) => {
+                    return Poll::Ready(Some(tracking_event))
+                }
+                Poll::Ready(Some(_)) => continue,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Bone, Palm, Quaternion, Vector3};
+
+impl From<Vector3> for glam::Vec3 {
+    fn from(vector: Vector3) -> glam::Vec3 {
+        glam::Vec3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<glam::Vec3> for Vector3 {
+    fn from(vector: glam::Vec3) -> Vector3 {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for glam::Vec3A {
+    fn from(vector: Vector3) -> glam::Vec3A {
+        glam::Vec3A::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<glam::Vec3A> for Vector3 {
+    fn from(vector: glam::Vec3A) -> Vector3 {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Quaternion> for glam::Quat {
+    fn from(quaternion: Quaternion) -> glam::Quat {
+        glam::Quat::from_xyzw(quaternion.x, quaternion.y, quaternion.z, quaternion.w)
+    }
+}
+
+impl From<glam::Quat> for Quaternion {
+    fn from(quaternion: glam::Quat) -> Quaternion {
+        Quaternion::new(quaternion.x, quaternion.y, quaternion.z, quaternion.w)
+    }
+}
+
+/// Pose of the bone, placed at its `prev_joint`.
+impl From<&Bone> for glam::Affine3A {
+    fn from(bone: &Bone) -> glam::Affine3A {
+        glam::Affine3A::from_rotation_translation(bone.rotation.into(), bone.prev_joint.into())
+    }
+}
+
+/// Pose of the palm, placed at its `position`.
+impl From<&Palm> for glam::Affine3A {
+    fn from(palm: &Palm) -> glam::Affine3A {
+        glam::Affine3A::from_rotation_translation(palm.orientation.into(), palm.position.into())
+    }
+}
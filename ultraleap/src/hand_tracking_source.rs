@@ -0,0 +1,88 @@
+#[cfg(feature = "async")]
+use crate::event_stream::*;
+use crate::{
+    ConnectionStatus, DeliveryPolicy, DeviceInfo, EventReceiver, Frames, LeapEvent, Subscription,
+    TrackingEvent,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Source of tracking events, implemented by [`LeapController`](crate::LeapController),
+/// [`PlaybackController`](crate::PlaybackController) and
+/// [`ScriptedSource`](crate::ScriptedSource) so applications can swap them.
+pub trait HandTrackingSource {
+    /// The source's own receiver, whose pending events the methods taking
+    /// events share.
+    fn receiver(&self) -> &EventReceiver;
+
+    /// Creates another receiver of all events from now on, independent of
+    /// the source's own pending events and those of other receivers.
+    fn subscribe(&self, policy: DeliveryPolicy) -> EventReceiver;
+
+    /// Calls `callback` for every tracking event, from the thread producing
+    /// the events, in addition to queueing it.
+    fn on_tracking(&self, callback: Box<dyn FnMut(&TrackingEvent) + Send>) -> Subscription;
+
+    /// Calls `callback` from the thread producing the events when a device is
+    /// attached.
+    fn on_device_attached(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription;
+
+    /// Calls `callback` from the thread producing the events when a device is
+    /// lost.
+    fn on_device_lost(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription;
+
+    /// Calls `callback` from the thread producing the events when the
+    /// connection to the service is lost.
+    fn on_connection_lost(&self, callback: Box<dyn FnMut() + Send>) -> Subscription;
+
+    /// The currently attached devices.
+    fn devices(&self) -> Vec<DeviceInfo>;
+
+    /// The current state of the connection to the tracking service, or what
+    /// the source reports in its place.
+    fn connection_status(&self) -> ConnectionStatus;
+
+    /// Takes the next pending event of any type.
+    fn poll_event(&mut self) -> Option<LeapEvent> {
+        self.receiver().poll_event()
+    }
+
+    /// Takes the next pending tracking event, skipping all other events.
+    fn get_tracking_event(&mut self) -> Option<Arc<TrackingEvent>> {
+        self.receiver().get_tracking_event()
+    }
+
+    /// Waits up to `timeout` for the next tracking event, skipping all other events.
+    fn wait_tracking_event(&mut self, timeout: Duration) -> Option<Arc<TrackingEvent>> {
+        self.receiver().wait_tracking_event(timeout)
+    }
+
+    /// Blocking iterator over the tracking events, it ends when the source
+    /// ends or its connection is lost.
+    fn frames(&mut self) -> Frames<'_> {
+        self.receiver().frames()
+    }
+
+    /// Stream of all events, sharing the pending events with
+    /// [`HandTrackingSource::poll_event`].
+    #[cfg(feature = "async")]
+    fn event_stream(&self) -> EventStream {
+        self.receiver().event_stream()
+    }
+
+    /// Stream of the tracking events, skipping all other events.
+    #[cfg(feature = "async")]
+    fn tracking_stream(&self) -> TrackingStream {
+        self.receiver().tracking_stream()
+    }
+
+    /// Takes all pending tracking events, oldest first, skipping all other events.
+    fn drain(&mut self) -> Vec<Arc<TrackingEvent>> {
+        self.receiver().drain()
+    }
+
+    /// Changes how pending tracking frames are kept, see [`DeliveryPolicy`].
+    fn set_delivery_policy(&self, policy: DeliveryPolicy) {
+        self.receiver().set_delivery_policy(policy)
+    }
+}
@@ -1,20 +1,82 @@
+#[cfg(feature = "async")]
+use crate::event_stream::*;
 use crate::{
-    tracking_event::*, LeapCloseConnection, LeapCreateConnection, LeapGetDeviceInfo,
-    LeapOpenConnection, LeapOpenDevice, LeapPollConnection, _eLeapEventType_eLeapEventType_Device,
-    _eLeapEventType_eLeapEventType_Tracking, _eLeapRS_eLeapRS_Success, LEAP_CONNECTION,
-    LEAP_CONNECTION_CONFIG, LEAP_CONNECTION_MESSAGE, LEAP_DEVICE, LEAP_DEVICE_INFO,
+    _eLeapConnectionConfig_eLeapConnectionConfig_MultiDeviceAware,
+    _eLeapEventType_eLeapEventType_Device, _eLeapVersionPart_eLeapVersionPart_ClientLibrary,
+    _eLeapVersionPart_eLeapVersionPart_ServerLibrary,
+    connection_status::ConnectionStatus,
+    controller_config::ControllerConfig,
+    eLeapVersionPart,
+    event_hub::EventHub,
+    event_queue::*,
+    event_receiver::*,
+    leap_error::leap_result,
+    leap_event::LeapEvent,
+    leapc::{
+        self, LeapCloseConnection, LeapCloseDevice, LeapCreateConnection, LeapDestroyConnection,
+        LeapGetConnectionInfo, LeapGetTrackingMode, LeapGetVersion, LeapOpenConnection,
+        LeapOpenDevice, LeapPollConnection, LeapSetPolicyFlags, LeapSetPolicyFlagsEx,
+        LeapSetTrackingMode, LeapSetTrackingModeEx, LeapSubscribeEvents, LeapUnsubscribeEvents,
+    },
+    subscription::*,
+    tracking_event::*,
+    DeviceInfo, HandTrackingSource, LeapError, PolicyFlags, TrackingMode, Version, LEAP_CONNECTION,
+    LEAP_CONNECTION_CONFIG, LEAP_CONNECTION_INFO, LEAP_CONNECTION_MESSAGE, LEAP_DEVICE,
+    LEAP_DEVICE_REF, LEAP_VERSION,
 };
 use log::{error, info, trace, warn};
-use std::mem::{size_of, transmute, MaybeUninit};
+use std::collections::HashMap;
+use std::mem::{size_of, MaybeUninit};
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, *};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
+
+/// LeapC connection handle shared by the controller and its polling thread,
+/// closed and destroyed once both are done with it.
+struct Connection(LEAP_CONNECTION);
+
+// LeapC connection handles may be used from any thread, only polling must not
+// happen concurrently which is left to the single polling thread.
+unsafe impl Send for Connection {}
+unsafe impl Sync for Connection {}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        unsafe {
+            LeapCloseConnection(self.0);
+            LeapDestroyConnection(self.0);
+        }
+    }
+}
+
+/// Opened LeapC device handle together with its last known info.
+struct Device {
+    handle: LEAP_DEVICE,
+    info: DeviceInfo,
+}
+
+unsafe impl Send for Device {}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        unsafe {
+            LeapCloseDevice(self.handle);
+        }
+    }
+}
 
 pub struct LeapController {
     running: bool,
+    connection: Option<Arc<Connection>>,
+    connection_lost: Arc<AtomicBool>,
+    devices: Arc<Mutex<Vec<Device>>>,
+    tracking_mode: Arc<Mutex<TrackingMode>>,
     polling_thread: Option<thread::JoinHandle<()>>,
     stop_sender: Option<Sender<bool>>,
-    tracking_event_receiver: Option<Receiver<TrackingEvent>>,
+    events: EventHub,
 }
 
 impl Default for LeapController {
@@ -24,52 +86,101 @@ impl Default for LeapController {
 }
 
 impl LeapController {
+    /// Creates a controller without waiting for the tracking service, a
+    /// failing connection is only logged and leaves a controller that never
+    /// yields frames, see [`LeapController::try_new`].
     pub fn new() -> LeapController {
-        let mut leap_controller = LeapController {
+        let mut leap_controller = Self::disconnected();
+        if let Err(error) = leap_controller.open_connection(&ControllerConfig::default()) {
+            error!("failed to open connection, error: {}", error);
+            leap_controller.events.broadcast().close();
+        }
+        leap_controller
+    }
+
+    /// Creates a controller and waits for the tracking service to accept the
+    /// connection, failing with `NotConnected` when the service refuses it and
+    /// with `Timeout` when it does not answer in time.
+    pub fn try_new() -> Result<LeapController, LeapError> {
+        Self::try_with_config(ControllerConfig::default())
+    }
+
+    /// Like [`LeapController::try_new`] with non default options.
+    pub fn try_with_config(config: ControllerConfig) -> Result<LeapController, LeapError> {
+        let mut leap_controller = Self::disconnected();
+        // subscribed before polling starts to not miss the connection event
+        let connection_events = leap_controller.subscribe(DeliveryPolicy::LatestOnly);
+        leap_controller.open_connection(&config)?;
+        if let Some(timeout) = config.connection_timeout {
+            wait_for_connection(&connection_events, timeout)?;
+        }
+        Ok(leap_controller)
+    }
+
+    fn disconnected() -> LeapController {
+        LeapController {
             running: false,
+            connection: None,
+            connection_lost: Arc::new(AtomicBool::new(false)),
+            devices: Arc::new(Mutex::new(vec![])),
+            tracking_mode: Arc::new(Mutex::new(TrackingMode::Unknown)),
             polling_thread: None,
             stop_sender: None,
-            tracking_event_receiver: None,
-        };
-        leap_controller.open_connection();
-        leap_controller
+            events: EventHub::new(),
+        }
     }
 
-    fn open_connection(&mut self) {
+    fn open_connection(&mut self, config: &ControllerConfig) -> Result<(), LeapError> {
         if self.running {
             warn!("already running");
-            return;
+            return Ok(());
         }
+        leapc::load()?;
+
+        let connection = unsafe {
+            let mut leap_connection_config: MaybeUninit<LEAP_CONNECTION_CONFIG> =
+                MaybeUninit::zeroed();
+            (*leap_connection_config.as_mut_ptr()).size =
+                size_of::<LEAP_CONNECTION_CONFIG>() as u32;
+            if config.multi_device_aware {
+                (*leap_connection_config.as_mut_ptr()).flags =
+                    _eLeapConnectionConfig_eLeapConnectionConfig_MultiDeviceAware as u32;
+            }
+            let mut leap_connection: LEAP_CONNECTION = ptr::null_mut();
+            info!("creating and opening connection");
+            leap_result(LeapCreateConnection(
+                leap_connection_config.as_ptr(),
+                &mut leap_connection,
+            ))?;
+            // from here on the handle is destroyed when dropped
+            let connection = Arc::new(Connection(leap_connection));
+            leap_result(LeapOpenConnection(connection.0))?;
+            info!("connection created and open");
+            connection
+        };
+
         self.running = true;
 
         let (stop_sender, stop_receiver) = mpsc::channel();
-        let (tracking_event_sender, tracking_event_receiver) = mpsc::channel();
 
+        self.connection = Some(connection.clone());
         self.stop_sender = Some(stop_sender);
-        self.tracking_event_receiver = Some(tracking_event_receiver);
+        self.events
+            .receiver()
+            .set_delivery_policy(config.delivery_policy);
+        let broadcast = self.events.broadcast().clone();
+        let connection_lost = self.connection_lost.clone();
+        let devices = self.devices.clone();
+        let callbacks = self.events.callbacks().clone();
+        let tracking_mode = self.tracking_mode.clone();
         self.polling_thread = Some(thread::spawn(move || {
             info!("start polling thread");
+            let _close_on_drop = CloseOnDrop(&broadcast);
             unsafe {
-                let leap_connection_config: MaybeUninit<LEAP_CONNECTION_CONFIG> =
-                    MaybeUninit::zeroed();
-                let mut leap_connection: LEAP_CONNECTION = ptr::null_mut();
-                info!("creating and opening connection");
-                let mut result =
-                    LeapCreateConnection(leap_connection_config.as_ptr(), &mut leap_connection);
-                if result != _eLeapRS_eLeapRS_Success {
-                    error!("failed to create connection, error: {}", result);
-                    return;
-                }
-
-                result = LeapOpenConnection(leap_connection);
-                if result != _eLeapRS_eLeapRS_Success {
-                    error!("failed to open connection, error: {}", result);
-                    return;
-                }
-
-                info!("connection created and open");
+                let leap_connection = connection.0;
                 let mut running = true;
-                let mut last_frame_id = 0;
+                // frame ids are counted per device
+                let mut last_frame_ids: HashMap<u32, i64> = HashMap::new();
 
                 while running {
                     if let Ok(stopped) = stop_receiver.try_recv() {
@@ -81,99 +192,396 @@ impl LeapController {
                     trace!("polling");
                     let mut leap_connection_message: MaybeUninit<LEAP_CONNECTION_MESSAGE> =
                         MaybeUninit::zeroed();
-                    result = LeapPollConnection(
+                    if let Err(error) = leap_result(LeapPollConnection(
                         leap_connection,
                         1000,
                         leap_connection_message.as_mut_ptr(),
-                    );
-
-                    if result != _eLeapRS_eLeapRS_Success {
-                        error!("failed to poll connection, error: {:#x}", result);
+                    )) {
+                        error!("failed to poll connection, error: {}", error);
                         continue;
                     }
-                    let type_ = leap_connection_message.as_ptr().read_unaligned().type_;
-                    if type_ == _eLeapEventType_eLeapEventType_Device {
-                        let raw_device_event = *leap_connection_message
-                            .as_ptr()
-                            .read_unaligned()
-                            .__bindgen_anon_1
-                            .device_event;
-                        let raw_device_ref = raw_device_event.device;
-                        let device_id = raw_device_ref.id;
-                        info!("device event with id {}", device_id);
-
-                        // let mut leap_device: MaybeUninit<LEAP_DEVICE> = MaybeUninit::uninit();
-                        let mut leap_device: LEAP_DEVICE = ptr::null_mut();
-                        result = LeapOpenDevice(raw_device_ref, &mut leap_device);
-                        if result != _eLeapRS_eLeapRS_Success {
-                            error!("failed to open device, error: {}", result);
-                            continue;
+                    let raw_message = leap_connection_message.assume_init();
+                    let type_ = raw_message.type_;
+                    if type_ == _eLeapEventType_eLeapEventType_Device
+                        && !raw_message.__bindgen_anon_1.device_event.is_null()
+                    {
+                        let raw_device_ref = (*raw_message.__bindgen_anon_1.device_event).device;
+                        info!("device event with id {}", raw_device_ref.id);
+                        match open_device(raw_device_ref) {
+                            Ok(device) => {
+                                info!("device attached: {:?}", device.info);
+                                callbacks.device_attached.invoke(&device.info);
+                                let mut devices = devices.lock().unwrap();
+                                devices.retain(|attached| attached.info.id != device.info.id);
+                                devices.push(device);
+                            }
+                            Err(error) => error!("failed to open device, error: {}", error),
                         }
-
-                        let mut leap_device_info: MaybeUninit<LEAP_DEVICE_INFO> =
-                            MaybeUninit::zeroed();
-
-                        // let mut serial: MaybeUninit<[i8; 256]> = MaybeUninit::zeroed();
-                        const SERIAL_SIZE: usize = 1000;
-                        let leap_device_info_size = size_of::<LEAP_DEVICE_INFO>();
-                        let mut serial: [i8; SERIAL_SIZE] = [0; SERIAL_SIZE];
-                        (*leap_device_info.as_mut_ptr()).serial_length = (SERIAL_SIZE - 1) as u32;
-                        (*leap_device_info.as_mut_ptr()).serial = serial.as_mut_ptr();
-                        (*leap_device_info.as_mut_ptr()).size = leap_device_info_size as u32;
-                        result = LeapGetDeviceInfo(leap_device, leap_device_info.as_mut_ptr());
-                        if result != _eLeapRS_eLeapRS_Success {
-                            error!("failed to get device info, error: {}", result);
-                            continue;
-                        }
-
-                        let h_fov = leap_device_info.as_ptr().read_unaligned().h_fov;
-                        let v_fov = leap_device_info.as_ptr().read_unaligned().v_fov;
-                        let range = leap_device_info.as_ptr().read_unaligned().range;
-                        let serial_string = std::str::from_utf8_unchecked(transmute(&serial[..]));
-                        info!(
-                            "device info: serial: '{}' h_fov: {}, v_fov: {}, range: {}",
-                            serial_string, h_fov, v_fov, range
-                        );
                     }
-                    if type_ == _eLeapEventType_eLeapEventType_Tracking {
-                        let raw_tracking_event = *leap_connection_message
-                            .as_ptr()
-                            .read_unaligned()
-                            .__bindgen_anon_1
-                            .tracking_event;
-
-                        if raw_tracking_event.tracking_frame_id != last_frame_id {
-                            last_frame_id = raw_tracking_event.tracking_frame_id;
-
-                            let tracking_event = TrackingEvent::from_raw(&raw_tracking_event);
 
-                            tracking_event_sender.send(tracking_event).unwrap();
+                    let event = match LeapEvent::from_raw(&raw_message) {
+                        Some(LeapEvent::Tracking(mut tracking_event)) => {
+                            let last_frame_id =
+                                last_frame_ids.entry(tracking_event.device_id).or_default();
+                            if tracking_event.event_id == *last_frame_id {
+                                continue;
+                            }
+                            *last_frame_id = tracking_event.event_id;
+                            if let Some(tracking_event) = Arc::get_mut(&mut tracking_event) {
+                                tracking_event.interaction_box =
+                                    InteractionBox::for_mode(*tracking_mode.lock().unwrap());
+                            }
+                            callbacks.tracking.invoke(&tracking_event);
+                            LeapEvent::Tracking(tracking_event)
                         }
-                    }
+                        Some(LeapEvent::Connection(connection_event)) => {
+                            info!("connected to service");
+                            connection_lost.store(false, Ordering::SeqCst);
+                            // answered by a tracking mode event
+                            if let Err(error) = leap_result(LeapGetTrackingMode(leap_connection)) {
+                                warn!("failed to request tracking mode, error: {}", error);
+                            }
+                            LeapEvent::Connection(connection_event)
+                        }
+                        Some(LeapEvent::DeviceLost(device_event)) => {
+                            info!("device with id {} lost", device_event.device_id);
+                            let lost_device = {
+                                let mut devices = devices.lock().unwrap();
+                                devices
+                                    .iter()
+                                    .position(|device| device.info.id == device_event.device_id)
+                                    .map(|position| devices.remove(position))
+                            };
+                            // callbacks may look up the devices themselves
+                            if let Some(device) = lost_device {
+                                callbacks.device_lost.invoke(&device.info);
+                            }
+                            LeapEvent::DeviceLost(device_event)
+                        }
+                        Some(LeapEvent::DeviceStatusChange(status_change)) => {
+                            let mut devices = devices.lock().unwrap();
+                            if let Some(device) = devices
+                                .iter_mut()
+                                .find(|device| device.info.id == status_change.device_id)
+                            {
+                                device.info.status = status_change.status;
+                            }
+                            LeapEvent::DeviceStatusChange(status_change)
+                        }
+                        Some(LeapEvent::Policy(policy_event)) => {
+                            info!("policy changed to {:?}", policy_event.current_policy);
+                            callbacks
+                                .policy_changed
+                                .invoke(&policy_event.current_policy);
+                            LeapEvent::Policy(policy_event)
+                        }
+                        Some(LeapEvent::TrackingMode(tracking_mode_event)) => {
+                            let mode = tracking_mode_event.current_tracking_mode;
+                            info!("tracking mode changed to {:?}", mode);
+                            *tracking_mode.lock().unwrap() = mode;
+                            callbacks.tracking_mode_changed.invoke(&mode);
+                            LeapEvent::TrackingMode(tracking_mode_event)
+                        }
+                        Some(LeapEvent::ConnectionLost) => {
+                            warn!("connection to service lost");
+                            connection_lost.store(true, Ordering::SeqCst);
+                            callbacks.connection_lost.invoke(&());
+                            LeapEvent::ConnectionLost
+                        }
+                        Some(event) => event,
+                        None => continue,
+                    };
+                    broadcast.push(event);
 
                     trace!("polled {}", type_);
                 }
-
-                LeapCloseConnection(leap_connection);
             }
             info!("end polling thread")
         }));
+        Ok(())
     }
 
     fn close_connection(&mut self) {
-        self.stop_sender.take().unwrap().send(true).unwrap();
-        self.polling_thread.take().unwrap().join().unwrap();
+        // the polling thread is gone already if a callback panicked
+        let _ = self.stop_sender.take().unwrap().send(true);
+        if self.polling_thread.take().unwrap().join().is_err() {
+            error!("polling thread panicked");
+        }
+        // devices have to be closed before their connection
+        self.devices.lock().unwrap().clear();
+        self.connection = None;
+    }
+
+    /// The current state of the connection to the tracking service.
+    pub fn connection_status(&self) -> ConnectionStatus {
+        let connection = match self.connection {
+            Some(ref connection) => connection,
+            _ => return ConnectionStatus::NotConnected,
+        };
+        if self.connection_lost.load(Ordering::SeqCst) {
+            return ConnectionStatus::Lost;
+        }
+        unsafe {
+            let mut leap_connection_info: MaybeUninit<LEAP_CONNECTION_INFO> = MaybeUninit::zeroed();
+            (*leap_connection_info.as_mut_ptr()).size = size_of::<LEAP_CONNECTION_INFO>() as u32;
+            if let Err(error) = leap_result(LeapGetConnectionInfo(
+                connection.0,
+                leap_connection_info.as_mut_ptr(),
+            )) {
+                warn!("failed to get connection info, error: {}", error);
+                return ConnectionStatus::NotConnected;
+            }
+            ConnectionStatus::from_raw(leap_connection_info.assume_init().status)
+        }
+    }
+
+    /// Version of the LeapC client library.
+    pub fn client_version(&self) -> Result<Version, LeapError> {
+        self.version(_eLeapVersionPart_eLeapVersionPart_ClientLibrary)
+    }
+
+    /// Version of the tracking service.
+    pub fn service_version(&self) -> Result<Version, LeapError> {
+        self.version(_eLeapVersionPart_eLeapVersionPart_ServerLibrary)
+    }
+
+    fn version(&self, part: eLeapVersionPart) -> Result<Version, LeapError> {
+        let connection = match self.connection {
+            Some(ref connection) => connection,
+            _ => return Err(LeapError::NotConnected),
+        };
+        unsafe {
+            let mut leap_version: MaybeUninit<LEAP_VERSION> = MaybeUninit::zeroed();
+            leap_result(LeapGetVersion(
+                connection.0,
+                part,
+                leap_version.as_mut_ptr(),
+            ))?;
+            Ok(Version::from_raw(&leap_version.assume_init()))
+        }
+    }
+
+    /// The currently attached devices.
+    pub fn devices(&self) -> Vec<DeviceInfo> {
+        self.devices
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|device| device.info.clone())
+            .collect()
     }
 
-    pub fn get_tracking_event(&mut self) -> Option<TrackingEvent> {
-        match self.tracking_event_receiver {
-            Some(ref receiver) => match receiver.try_recv() {
-                Ok(tracking_event) => Some(tracking_event),
-                _ => None,
-            },
-            _ => None,
+    /// Looks up the info of an attached device from any thread.
+    pub(crate) fn device_lookup(&self) -> impl Fn(u32) -> Option<DeviceInfo> + Send + 'static {
+        let devices = self.devices.clone();
+        move |device_id| {
+            devices
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|device| device.info.id == device_id)
+                .map(|device| device.info.clone())
         }
     }
+
+    /// Starts receiving the events of a device, only needed for a
+    /// [`ControllerConfig::multi_device_aware`] controller.
+    pub fn subscribe_device(&self, device_id: u32) -> Result<(), LeapError> {
+        self.with_device(device_id, |connection, device| unsafe {
+            leap_result(LeapSubscribeEvents(connection, device))
+        })
+    }
+
+    /// Stops receiving the events of a device.
+    pub fn unsubscribe_device(&self, device_id: u32) -> Result<(), LeapError> {
+        self.with_device(device_id, |connection, device| unsafe {
+            leap_result(LeapUnsubscribeEvents(connection, device))
+        })
+    }
+
+    /// Requests the `set` policies to be enabled and the `clear` policies to
+    /// be disabled, the service confirms the change with a
+    /// [`LeapEvent::Policy`], see also [`LeapController::on_policy_changed`].
+    pub fn set_policy(&self, set: PolicyFlags, clear: PolicyFlags) -> Result<(), LeapError> {
+        let connection = match self.connection {
+            Some(ref connection) => connection,
+            _ => return Err(LeapError::NotConnected),
+        };
+        unsafe {
+            leap_result(LeapSetPolicyFlags(
+                connection.0,
+                set.bits() as u64,
+                clear.bits() as u64,
+            ))
+        }
+    }
+
+    /// Like [`LeapController::set_policy`] for a single device.
+    pub fn set_device_policy(
+        &self,
+        device_id: u32,
+        set: PolicyFlags,
+        clear: PolicyFlags,
+    ) -> Result<(), LeapError> {
+        self.with_device(device_id, |connection, device| unsafe {
+            leap_result(LeapSetPolicyFlagsEx(
+                connection,
+                device,
+                set.bits() as u64,
+                clear.bits() as u64,
+            ))
+        })
+    }
+
+    /// Requests the service to optimize tracking for `mode`, the service
+    /// confirms the change with a [`LeapEvent::TrackingMode`].
+    pub fn set_tracking_mode(&self, mode: TrackingMode) -> Result<(), LeapError> {
+        let connection = match self.connection {
+            Some(ref connection) => connection,
+            _ => return Err(LeapError::NotConnected),
+        };
+        if mode == TrackingMode::Unknown {
+            return Err(LeapError::InvalidArgument);
+        }
+        unsafe { leap_result(LeapSetTrackingMode(connection.0, mode.to_raw())) }
+    }
+
+    /// Like [`LeapController::set_tracking_mode`] for a single device.
+    pub fn set_device_tracking_mode(
+        &self,
+        device_id: u32,
+        mode: TrackingMode,
+    ) -> Result<(), LeapError> {
+        if mode == TrackingMode::Unknown {
+            return Err(LeapError::InvalidArgument);
+        }
+        self.with_device(device_id, |connection, device| unsafe {
+            leap_result(LeapSetTrackingModeEx(connection, device, mode.to_raw()))
+        })
+    }
+
+    /// The tracking mode last reported by the service, `Unknown` until the
+    /// first report after connecting.
+    pub fn tracking_mode(&self) -> TrackingMode {
+        *self.tracking_mode.lock().unwrap()
+    }
+
+    fn with_device<F>(&self, device_id: u32, f: F) -> Result<(), LeapError>
+    where
+        F: FnOnce(LEAP_CONNECTION, LEAP_DEVICE) -> Result<(), LeapError>,
+    {
+        let connection = match self.connection {
+            Some(ref connection) => connection,
+            _ => return Err(LeapError::NotConnected),
+        };
+        let devices = self.devices.lock().unwrap();
+        match devices.iter().find(|device| device.info.id == device_id) {
+            Some(device) => f(connection.0, device.handle),
+            _ => Err(LeapError::InvalidArgument),
+        }
+    }
+
+    /// Changes how pending tracking frames are kept, see [`DeliveryPolicy`].
+    pub fn set_delivery_policy(&self, policy: DeliveryPolicy) {
+        self.events.receiver().set_delivery_policy(policy);
+    }
+
+    /// Creates another receiver of all events from now on, independent of
+    /// the controller's own pending events and those of other receivers.
+    pub fn subscribe(&self, policy: DeliveryPolicy) -> EventReceiver {
+        self.events.subscribe(policy)
+    }
+
+    /// Calls `callback` from the polling thread for every tracking event, in
+    /// addition to queueing it.
+    pub fn on_tracking<F>(&self, callback: F) -> Subscription
+    where
+        F: FnMut(&TrackingEvent) + Send + 'static,
+    {
+        self.events.on_tracking(Box::new(callback))
+    }
+
+    /// Calls `callback` from the polling thread when a device is attached.
+    pub fn on_device_attached<F>(&self, callback: F) -> Subscription
+    where
+        F: FnMut(&DeviceInfo) + Send + 'static,
+    {
+        self.events.on_device_attached(Box::new(callback))
+    }
+
+    /// Calls `callback` from the polling thread when a device is lost.
+    pub fn on_device_lost<F>(&self, callback: F) -> Subscription
+    where
+        F: FnMut(&DeviceInfo) + Send + 'static,
+    {
+        self.events.on_device_lost(Box::new(callback))
+    }
+
+    /// Calls `callback` from the polling thread when the connection to the
+    /// service is lost.
+    pub fn on_connection_lost<F>(&self, callback: F) -> Subscription
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.events.on_connection_lost(Box::new(callback))
+    }
+
+    /// Calls `callback` from the polling thread with the policies in effect
+    /// whenever the service reports a policy change.
+    pub fn on_policy_changed<F>(&self, callback: F) -> Subscription
+    where
+        F: FnMut(&PolicyFlags) + Send + 'static,
+    {
+        self.events.on_policy_changed(Box::new(callback))
+    }
+
+    /// Calls `callback` from the polling thread when the service reports the
+    /// tracking mode.
+    pub fn on_tracking_mode_changed<F>(&self, callback: F) -> Subscription
+    where
+        F: FnMut(&TrackingMode) + Send + 'static,
+    {
+        self.events.on_tracking_mode_changed(Box::new(callback))
+    }
+
+    /// Takes the next pending event of any type.
+    pub fn poll_event(&mut self) -> Option<LeapEvent> {
+        self.events.receiver().poll_event()
+    }
+
+    /// Takes the next pending tracking event, skipping all other events.
+    pub fn get_tracking_event(&mut self) -> Option<Arc<TrackingEvent>> {
+        self.events.receiver().get_tracking_event()
+    }
+
+    /// Waits up to `timeout` for the next tracking event, skipping all other events.
+    pub fn wait_tracking_event(&mut self, timeout: Duration) -> Option<Arc<TrackingEvent>> {
+        self.events.receiver().wait_tracking_event(timeout)
+    }
+
+    /// Blocking iterator over the tracking events, it ends when the connection
+    /// to the service is lost or closed.
+    pub fn frames(&mut self) -> Frames<'_> {
+        self.events.receiver().frames()
+    }
+
+    /// Stream of all events, sharing the pending events with
+    /// [`LeapController::poll_event`].
+    #[cfg(feature = "async")]
+    pub fn event_stream(&self) -> EventStream {
+        self.events.receiver().event_stream()
+    }
+
+    /// Stream of the tracking events, skipping all other events.
+    #[cfg(feature = "async")]
+    pub fn tracking_stream(&self) -> TrackingStream {
+        self.events.receiver().tracking_stream()
+    }
+
+    /// Takes all pending tracking events, oldest first, skipping all other events.
+    pub fn drain(&mut self) -> Vec<Arc<TrackingEvent>> {
+        self.events.receiver().drain()
+    }
 }
 
 impl Drop for LeapController {
@@ -184,3 +592,65 @@ impl Drop for LeapController {
         }
     }
 }
+
+fn wait_for_connection(receiver: &EventReceiver, timeout: Duration) -> Result<(), LeapError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match receiver.wait_event(remaining) {
+            Some(LeapEvent::Connection(_)) => return Ok(()),
+            Some(LeapEvent::ConnectionLost) => return Err(LeapError::NotConnected),
+            Some(_) => {}
+            None if remaining.is_zero() || receiver.is_finished() => {
+                return Err(LeapError::Timeout)
+            }
+            None => {}
+        }
+    }
+}
+
+unsafe fn open_device(raw_device_ref: LEAP_DEVICE_REF) -> Result<Device, LeapError> {
+    let mut leap_device: LEAP_DEVICE = ptr::null_mut();
+    leap_result(LeapOpenDevice(raw_device_ref, &mut leap_device))?;
+    // from here on the handle is closed when dropped
+    let mut device = Device {
+        handle: leap_device,
+        info: DeviceInfo::default(),
+    };
+    device.info = DeviceInfo::from_device(raw_device_ref.id, leap_device)?;
+    Ok(device)
+}
+
+impl HandTrackingSource for LeapController {
+    fn receiver(&self) -> &EventReceiver {
+        self.events.receiver()
+    }
+
+    fn subscribe(&self, policy: DeliveryPolicy) -> EventReceiver {
+        self.events.subscribe(policy)
+    }
+
+    fn on_tracking(&self, callback: Box<dyn FnMut(&TrackingEvent) + Send>) -> Subscription {
+        self.events.on_tracking(callback)
+    }
+
+    fn on_device_attached(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        self.events.on_device_attached(callback)
+    }
+
+    fn on_device_lost(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        self.events.on_device_lost(callback)
+    }
+
+    fn on_connection_lost(&self, callback: Box<dyn FnMut() + Send>) -> Subscription {
+        self.events.on_connection_lost(callback)
+    }
+
+    fn devices(&self) -> Vec<DeviceInfo> {
+        LeapController::devices(self)
+    }
+
+    fn connection_status(&self) -> ConnectionStatus {
+        LeapController::connection_status(self)
+    }
+}
@@ -0,0 +1,151 @@
+use crate::*;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by a failing LeapC call, one variant per `eLeapRS` code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapError {
+    UnknownError,
+    InvalidArgument,
+    InsufficientResources,
+    InsufficientBuffer,
+    Timeout,
+    NotConnected,
+    HandshakeIncomplete,
+    BufferSizeOverflow,
+    ProtocolError,
+    InvalidClientID,
+    UnexpectedClosed,
+    UnknownImageFrameRequest,
+    UnknownTrackingFrameID,
+    RoutineIsNotSeer,
+    TimestampTooEarly,
+    ConcurrentPoll,
+    NotAvailable,
+    NotStreaming,
+    CannotOpenDevice,
+    Unsupported,
+    /// The LeapC library could not be loaded, only returned with the
+    /// `dynamic-loading` feature.
+    LibraryNotFound,
+    /// A code this version of the wrapper does not know about.
+    Unrecognized(eLeapRS),
+}
+
+impl LeapError {
+    /// Converts a raw `eLeapRS` code, `None` means `eLeapRS_Success`.
+    pub(crate) fn from_raw(result: eLeapRS) -> Option<LeapError> {
+        let error = match result {
+            _eLeapRS_eLeapRS_Success => return None,
+            _eLeapRS_eLeapRS_UnknownError => LeapError::UnknownError,
+            _eLeapRS_eLeapRS_InvalidArgument => LeapError::InvalidArgument,
+            _eLeapRS_eLeapRS_InsufficientResources => LeapError::InsufficientResources,
+            _eLeapRS_eLeapRS_InsufficientBuffer => LeapError::InsufficientBuffer,
+            _eLeapRS_eLeapRS_Timeout => LeapError::Timeout,
+            _eLeapRS_eLeapRS_NotConnected => LeapError::NotConnected,
+            _eLeapRS_eLeapRS_HandshakeIncomplete => LeapError::HandshakeIncomplete,
+            _eLeapRS_eLeapRS_BufferSizeOverflow => LeapError::BufferSizeOverflow,
+            _eLeapRS_eLeapRS_ProtocolError => LeapError::ProtocolError,
+            _eLeapRS_eLeapRS_InvalidClientID => LeapError::InvalidClientID,
+            _eLeapRS_eLeapRS_UnexpectedClosed => LeapError::UnexpectedClosed,
+            _eLeapRS_eLeapRS_UnknownImageFrameRequest => LeapError::UnknownImageFrameRequest,
+            _eLeapRS_eLeapRS_UnknownTrackingFrameID => LeapError::UnknownTrackingFrameID,
+            _eLeapRS_eLeapRS_RoutineIsNotSeer => LeapError::RoutineIsNotSeer,
+            _eLeapRS_eLeapRS_TimestampTooEarly => LeapError::TimestampTooEarly,
+            _eLeapRS_eLeapRS_ConcurrentPoll => LeapError::ConcurrentPoll,
+            _eLeapRS_eLeapRS_NotAvailable => LeapError::NotAvailable,
+            _eLeapRS_eLeapRS_NotStreaming => LeapError::NotStreaming,
+            _eLeapRS_eLeapRS_CannotOpenDevice => LeapError::CannotOpenDevice,
+            _eLeapRS_eLeapRS_Unsupported => LeapError::Unsupported,
+            other => LeapError::Unrecognized(other),
+        };
+        Some(error)
+    }
+
+    /// The raw `eLeapRS` code of this error, `LibraryNotFound` has none of its
+    /// own and gives the one of `NotAvailable`.
+    pub fn code(&self) -> eLeapRS {
+        match self {
+            LeapError::UnknownError => _eLeapRS_eLeapRS_UnknownError,
+            LeapError::InvalidArgument => _eLeapRS_eLeapRS_InvalidArgument,
+            LeapError::InsufficientResources => _eLeapRS_eLeapRS_InsufficientResources,
+            LeapError::InsufficientBuffer => _eLeapRS_eLeapRS_InsufficientBuffer,
+            LeapError::Timeout => _eLeapRS_eLeapRS_Timeout,
+            LeapError::NotConnected => _eLeapRS_eLeapRS_NotConnected,
+            LeapError::HandshakeIncomplete => _eLeapRS_eLeapRS_HandshakeIncomplete,
+            LeapError::BufferSizeOverflow => _eLeapRS_eLeapRS_BufferSizeOverflow,
+            LeapError::ProtocolError => _eLeapRS_eLeapRS_ProtocolError,
+            LeapError::InvalidClientID => _eLeapRS_eLeapRS_InvalidClientID,
+            LeapError::UnexpectedClosed => _eLeapRS_eLeapRS_UnexpectedClosed,
+            LeapError::UnknownImageFrameRequest => _eLeapRS_eLeapRS_UnknownImageFrameRequest,
+            LeapError::UnknownTrackingFrameID => _eLeapRS_eLeapRS_UnknownTrackingFrameID,
+            LeapError::RoutineIsNotSeer => _eLeapRS_eLeapRS_RoutineIsNotSeer,
+            LeapError::TimestampTooEarly => _eLeapRS_eLeapRS_TimestampTooEarly,
+            LeapError::ConcurrentPoll => _eLeapRS_eLeapRS_ConcurrentPoll,
+            LeapError::NotAvailable => _eLeapRS_eLeapRS_NotAvailable,
+            LeapError::NotStreaming => _eLeapRS_eLeapRS_NotStreaming,
+            LeapError::CannotOpenDevice => _eLeapRS_eLeapRS_CannotOpenDevice,
+            LeapError::Unsupported => _eLeapRS_eLeapRS_Unsupported,
+            LeapError::LibraryNotFound => _eLeapRS_eLeapRS_NotAvailable,
+            LeapError::Unrecognized(code) => *code,
+        }
+    }
+}
+
+impl fmt::Display for LeapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            LeapError::UnknownError => "an undetermined error has occurred",
+            LeapError::InvalidArgument => "an invalid argument was specified",
+            LeapError::InsufficientResources => {
+                "insufficient resources existed to complete the request"
+            }
+            LeapError::InsufficientBuffer => "the specified buffer was not large enough",
+            LeapError::Timeout => "the requested operation has timed out",
+            LeapError::NotConnected => {
+                "the operation is invalid because there is no current connection"
+            }
+            LeapError::HandshakeIncomplete => {
+                "the operation is invalid because the connection is not complete"
+            }
+            LeapError::BufferSizeOverflow => "the specified buffer size is too large",
+            LeapError::ProtocolError => "a communications protocol error occurred",
+            LeapError::InvalidClientID => "the server incorrectly specified zero as a client ID",
+            LeapError::UnexpectedClosed => "the connection to the service was unexpectedly closed",
+            LeapError::UnknownImageFrameRequest => {
+                "the specified request token does not appear to be valid"
+            }
+            LeapError::UnknownTrackingFrameID => {
+                "the specified frame ID is not valid or is no longer available"
+            }
+            LeapError::RoutineIsNotSeer => {
+                "the specified timestamp references a future point in time"
+            }
+            LeapError::TimestampTooEarly => {
+                "the specified timestamp references a point too far in the past"
+            }
+            LeapError::ConcurrentPoll => "LeapPollConnection is called concurrently",
+            LeapError::NotAvailable => "a connection to the service could not be established",
+            LeapError::NotStreaming => {
+                "the requested operation can only be performed while the device is sending data"
+            }
+            LeapError::CannotOpenDevice => "the specified device could not be opened",
+            LeapError::Unsupported => "the request is not supported by this version of the service",
+            LeapError::LibraryNotFound => {
+                return write!(f, "the LeapC library could not be loaded");
+            }
+            LeapError::Unrecognized(_) => "unrecognized error",
+        };
+        write!(f, "{} ({:#x})", description, self.code())
+    }
+}
+
+impl Error for LeapError {}
+
+/// Turns the result of a LeapC call into a `Result`.
+pub(crate) fn leap_result(result: eLeapRS) -> Result<(), LeapError> {
+    match LeapError::from_raw(result) {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
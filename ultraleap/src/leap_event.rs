@@ -0,0 +1,402 @@
+use crate::{tracking_event::*, *};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::slice;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionEvent {
+    /// `eLeapServiceDisposition` flags reported by the service.
+    pub flags: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceEvent {
+    pub device_id: u32,
+    /// `eLeapDeviceStatus` flags of the device.
+    pub status: u32,
+}
+
+impl DeviceEvent {
+    pub(crate) fn from_raw(raw_device_event: &_LEAP_DEVICE_EVENT) -> DeviceEvent {
+        DeviceEvent {
+            device_id: raw_device_event.device.id,
+            status: raw_device_event.status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceFailureEvent {
+    /// `eLeapDeviceStatus` failure code.
+    pub status: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceStatusChangeEvent {
+    pub device_id: u32,
+    pub last_status: u32,
+    pub status: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolicyEvent {
+    /// Flags in effect after a policy change was processed by the service.
+    pub current_policy: PolicyFlags,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Unknown,
+    Boolean(bool),
+    Int32(i32),
+    Float(f32),
+    String(String),
+}
+
+impl ConfigValue {
+    pub(crate) fn from_raw(raw_variant: &_LEAP_VARIANT) -> ConfigValue {
+        unsafe {
+            match raw_variant.type_ {
+                _eLeapValueType_eLeapValueType_Boolean => {
+                    ConfigValue::Boolean(raw_variant.__bindgen_anon_1.boolValue)
+                }
+                _eLeapValueType_eLeapValueType_Int32 => {
+                    ConfigValue::Int32(raw_variant.__bindgen_anon_1.iValue)
+                }
+                _eLeapValueType_eLeapValueType_Float => {
+                    ConfigValue::Float(raw_variant.__bindgen_anon_1.fValue)
+                }
+                _eLeapValueType_eLeapValueType_String => {
+                    ConfigValue::String(string_from_raw(raw_variant.__bindgen_anon_1.strValue))
+                }
+                _ => ConfigValue::Unknown,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigResponseEvent {
+    pub request_id: u32,
+    pub value: ConfigValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfigChangeEvent {
+    pub request_id: u32,
+    pub status: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSeverity {
+    Unknown,
+    Critical,
+    Warning,
+    Information,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEvent {
+    pub severity: LogSeverity,
+    pub timestamp: i64,
+    pub message: String,
+}
+
+impl LogEvent {
+    pub(crate) fn from_raw(raw_log_event: &_LEAP_LOG_EVENT) -> LogEvent {
+        let severity = match raw_log_event.severity {
+            _eLeapLogSeverity_eLeapLogSeverity_Critical => LogSeverity::Critical,
+            _eLeapLogSeverity_eLeapLogSeverity_Warning => LogSeverity::Warning,
+            _eLeapLogSeverity_eLeapLogSeverity_Information => LogSeverity::Information,
+            _ => LogSeverity::Unknown,
+        };
+        LogEvent {
+            severity,
+            timestamp: raw_log_event.timestamp,
+            message: unsafe { string_from_raw(raw_log_event.message) },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DroppedFrameEvent {
+    pub frame_id: i64,
+    /// `eLeapDroppedFrameType` of the queue the frame was dropped from.
+    pub type_: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub bytes_per_pixel: u32,
+    /// `eLeapImageFormat` of the pixel data.
+    pub format: u32,
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    pub(crate) fn from_raw(raw_image: &_LEAP_IMAGE) -> Image {
+        let properties = raw_image.properties;
+        let len = (properties.width * properties.height * properties.bpp) as usize;
+        let data = if raw_image.data.is_null() {
+            vec![]
+        } else {
+            unsafe {
+                let start = (raw_image.data as *const u8).add(raw_image.offset as usize);
+                slice::from_raw_parts(start, len).to_vec()
+            }
+        };
+        Image {
+            width: properties.width,
+            height: properties.height,
+            bytes_per_pixel: properties.bpp,
+            format: properties.format as u32,
+            data,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageEvent {
+    pub frame_id: i64,
+    pub timestamp: i64,
+    /// The left and right stereo images.
+    pub images: [Image; 2],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMappingChangeEvent {
+    pub frame_id: i64,
+    pub timestamp: i64,
+    pub n_points: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackingModeEvent {
+    /// Mode in effect after a mode change or request was processed by the service.
+    pub current_tracking_mode: TrackingMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeadPoseEvent {
+    pub timestamp: i64,
+    pub head_position: Vector3,
+    pub head_orientation: Quaternion,
+    pub head_linear_velocity: Vector3,
+    pub head_angular_velocity: Vector3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EyeEvent {
+    pub frame_id: i64,
+    pub timestamp: i64,
+    pub left_eye_position: Vector3,
+    pub right_eye_position: Vector3,
+    pub left_eye_estimated_error: f32,
+    pub right_eye_estimated_error: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuEvent {
+    pub timestamp: i64,
+    pub timestamp_hw: i64,
+    /// `eLeapIMUFlag` flags telling which of the readings are valid.
+    pub flags: u32,
+    pub accelerometer: Vector3,
+    pub gyroscope: Vector3,
+    pub temperature: f32,
+}
+
+/// An event received from the tracking service, one variant per `eLeapEventType`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeapEvent {
+    Connection(ConnectionEvent),
+    ConnectionLost,
+    Device(DeviceEvent),
+    DeviceLost(DeviceEvent),
+    DeviceFailure(DeviceFailureEvent),
+    DeviceStatusChange(DeviceStatusChangeEvent),
+    Policy(PolicyEvent),
+    /// Shared by all receivers of the frame.
+    Tracking(Arc<TrackingEvent>),
+    ImageRequestError,
+    ImageComplete,
+    Log(LogEvent),
+    LogEvents(Vec<LogEvent>),
+    ConfigResponse(ConfigResponseEvent),
+    ConfigChange(ConfigChangeEvent),
+    DroppedFrame(DroppedFrameEvent),
+    Image(ImageEvent),
+    PointMappingChange(PointMappingChangeEvent),
+    TrackingMode(TrackingModeEvent),
+    HeadPose(HeadPoseEvent),
+    Eyes(EyeEvent),
+    Imu(ImuEvent),
+}
+
+impl LeapEvent {
+    /// Converts a message polled through the `raw` API, `None` for empty or
+    /// unknown messages.
+    ///
+    /// # Safety
+    ///
+    /// The message must be as `LeapPollConnection` filled it: its event
+    /// pointer null or pointing to a valid event of its type, including the
+    /// pointers and lengths within the event, and the connection not polled
+    /// again since.
+    #[cfg(feature = "raw")]
+    pub unsafe fn from_leapc(raw_message: &_LEAP_CONNECTION_MESSAGE) -> Option<LeapEvent> {
+        LeapEvent::from_raw(raw_message)
+    }
+
+    /// Converts a polled message, `None` for empty or unknown messages.
+    pub(crate) fn from_raw(raw_message: &_LEAP_CONNECTION_MESSAGE) -> Option<LeapEvent> {
+        unsafe {
+            let raw_event = raw_message.__bindgen_anon_1;
+            if raw_event.pointer.is_null() {
+                return None;
+            }
+            let event = match raw_message.type_ {
+                _eLeapEventType_eLeapEventType_Connection => {
+                    LeapEvent::Connection(ConnectionEvent {
+                        flags: (*raw_event.connection_event).flags as u32,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_ConnectionLost => LeapEvent::ConnectionLost,
+                _eLeapEventType_eLeapEventType_Device => {
+                    LeapEvent::Device(DeviceEvent::from_raw(&*raw_event.device_event))
+                }
+                _eLeapEventType_eLeapEventType_DeviceLost => {
+                    LeapEvent::DeviceLost(DeviceEvent::from_raw(&*raw_event.device_event))
+                }
+                _eLeapEventType_eLeapEventType_DeviceFailure => {
+                    LeapEvent::DeviceFailure(DeviceFailureEvent {
+                        status: (*raw_event.device_failure_event).status as u32,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_DeviceStatusChange => {
+                    let raw_status_change = *raw_event.device_status_change_event;
+                    LeapEvent::DeviceStatusChange(DeviceStatusChangeEvent {
+                        device_id: raw_status_change.device.id,
+                        last_status: raw_status_change.last_status,
+                        status: raw_status_change.status,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_Policy => LeapEvent::Policy(PolicyEvent {
+                    current_policy: PolicyFlags::from_raw((*raw_event.policy_event).current_policy),
+                }),
+                _eLeapEventType_eLeapEventType_Tracking => {
+                    let mut tracking_event = TrackingEvent::from_raw(&*raw_event.tracking_event);
+                    tracking_event.device_id = raw_message.device_id;
+                    LeapEvent::Tracking(Arc::new(tracking_event))
+                }
+                _eLeapEventType_eLeapEventType_ImageRequestError => LeapEvent::ImageRequestError,
+                _eLeapEventType_eLeapEventType_ImageComplete => LeapEvent::ImageComplete,
+                _eLeapEventType_eLeapEventType_LogEvent => {
+                    LeapEvent::Log(LogEvent::from_raw(&*raw_event.log_event))
+                }
+                _eLeapEventType_eLeapEventType_LogEvents => {
+                    let raw_log_events = *raw_event.log_events;
+                    let log_events = (0..raw_log_events.nEvents)
+                        .map(|i| LogEvent::from_raw(&*raw_log_events.events.offset(i as isize)))
+                        .collect();
+                    LeapEvent::LogEvents(log_events)
+                }
+                _eLeapEventType_eLeapEventType_ConfigResponse => {
+                    let raw_config_response = *raw_event.config_response_event;
+                    LeapEvent::ConfigResponse(ConfigResponseEvent {
+                        request_id: raw_config_response.requestID,
+                        value: ConfigValue::from_raw(&raw_config_response.value),
+                    })
+                }
+                _eLeapEventType_eLeapEventType_ConfigChange => {
+                    let raw_config_change = *raw_event.config_change_event;
+                    LeapEvent::ConfigChange(ConfigChangeEvent {
+                        request_id: raw_config_change.requestID,
+                        status: raw_config_change.status,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_DroppedFrame => {
+                    let raw_dropped_frame = *raw_event.dropped_frame_event;
+                    LeapEvent::DroppedFrame(DroppedFrameEvent {
+                        frame_id: raw_dropped_frame.frame_id,
+                        type_: raw_dropped_frame.type_ as u32,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_Image => {
+                    let raw_image_event = &*raw_event.image_event;
+                    LeapEvent::Image(ImageEvent {
+                        frame_id: raw_image_event.info.frame_id,
+                        timestamp: raw_image_event.info.timestamp,
+                        images: [
+                            Image::from_raw(&raw_image_event.image[0]),
+                            Image::from_raw(&raw_image_event.image[1]),
+                        ],
+                    })
+                }
+                _eLeapEventType_eLeapEventType_PointMappingChange => {
+                    let raw_point_mapping = *raw_event.point_mapping_change_event;
+                    LeapEvent::PointMappingChange(PointMappingChangeEvent {
+                        frame_id: raw_point_mapping.frame_id,
+                        timestamp: raw_point_mapping.info.timestamp,
+                        n_points: raw_point_mapping.nPoints,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_TrackingMode => {
+                    LeapEvent::TrackingMode(TrackingModeEvent {
+                        current_tracking_mode: TrackingMode::from_raw(
+                            (*raw_event.tracking_mode_event).current_tracking_mode,
+                        ),
+                    })
+                }
+                _eLeapEventType_eLeapEventType_HeadPose => {
+                    let raw_head_pose = *raw_event.head_pose_event;
+                    LeapEvent::HeadPose(HeadPoseEvent {
+                        timestamp: raw_head_pose.timestamp,
+                        head_position: Vector3::from_raw(&raw_head_pose.head_position),
+                        head_orientation: Quaternion::from_raw(&raw_head_pose.head_orientation),
+                        head_linear_velocity: Vector3::from_raw(
+                            &raw_head_pose.head_linear_velocity,
+                        ),
+                        head_angular_velocity: Vector3::from_raw(
+                            &raw_head_pose.head_angular_velocity,
+                        ),
+                    })
+                }
+                _eLeapEventType_eLeapEventType_Eyes => {
+                    let raw_eye_event = *raw_event.eye_event;
+                    LeapEvent::Eyes(EyeEvent {
+                        frame_id: raw_eye_event.frame_id,
+                        timestamp: raw_eye_event.timestamp,
+                        left_eye_position: Vector3::from_raw(&raw_eye_event.left_eye_position),
+                        right_eye_position: Vector3::from_raw(&raw_eye_event.right_eye_position),
+                        left_eye_estimated_error: raw_eye_event.left_eye_estimated_error,
+                        right_eye_estimated_error: raw_eye_event.right_eye_estimated_error,
+                    })
+                }
+                _eLeapEventType_eLeapEventType_IMU => {
+                    let raw_imu_event = *raw_event.imu_event;
+                    LeapEvent::Imu(ImuEvent {
+                        timestamp: raw_imu_event.timestamp,
+                        timestamp_hw: raw_imu_event.timestamp_hw,
+                        flags: raw_imu_event.flags as u32,
+                        accelerometer: Vector3::from_raw(&raw_imu_event.accelerometer),
+                        gyroscope: Vector3::from_raw(&raw_imu_event.gyroscope),
+                        temperature: raw_imu_event.temperature,
+                    })
+                }
+                _ => return None,
+            };
+            Some(event)
+        }
+    }
+}
+
+pub(crate) unsafe fn string_from_raw(raw_string: *const c_char) -> String {
+    if raw_string.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(raw_string).to_string_lossy().into_owned()
+}
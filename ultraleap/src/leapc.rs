@@ -0,0 +1,207 @@
+use crate::LeapError;
+#[cfg(feature = "dynamic-loading")]
+use log::{debug, info, warn};
+#[cfg(feature = "dynamic-loading")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "dynamic-loading")]
+use std::sync::{Mutex, OnceLock};
+
+/// Declares the LeapC functions used by the wrapper. They call the linked
+/// library, or with the `dynamic-loading` feature the one loaded at runtime.
+macro_rules! leapc_functions {
+    ($(fn $name:ident($($arg:ident: $arg_type:ty),*) $(-> $result:ty)?;)*) => {
+        #[cfg(not(feature = "dynamic-loading"))]
+        mod functions {
+            use crate::*;
+
+            $(
+                pub(crate) unsafe fn $name($($arg: $arg_type),*) $(-> $result)? {
+                    ultraleap_sys::$name($($arg),*)
+                }
+            )*
+        }
+
+        #[cfg(feature = "dynamic-loading")]
+        mod functions {
+            use crate::*;
+
+            /// Entry points of the loaded library.
+            pub(super) struct Functions {
+                $($name: unsafe extern "C" fn($($arg_type),*) $(-> $result)?,)*
+            }
+
+            impl Functions {
+                pub(super) unsafe fn load(
+                    library: &libloading::Library,
+                ) -> Result<Functions, libloading::Error> {
+                    Ok(Functions {
+                        $($name: *library.get(concat!(stringify!($name), "\0").as_bytes())?,)*
+                    })
+                }
+            }
+
+            $(
+                pub(crate) unsafe fn $name($($arg: $arg_type),*) $(-> $result)? {
+                    (super::loaded_functions().$name)($($arg),*)
+                }
+            )*
+        }
+
+        pub(crate) use functions::*;
+    };
+}
+
+leapc_functions! {
+    fn LeapGetNow() -> i64;
+    fn LeapCreateConnection(
+        config: *const LEAP_CONNECTION_CONFIG,
+        connection: *mut LEAP_CONNECTION
+    ) -> eLeapRS;
+    fn LeapOpenConnection(connection: LEAP_CONNECTION) -> eLeapRS;
+    fn LeapPollConnection(
+        connection: LEAP_CONNECTION,
+        timeout: u32,
+        message: *mut LEAP_CONNECTION_MESSAGE
+    ) -> eLeapRS;
+    fn LeapGetConnectionInfo(
+        connection: LEAP_CONNECTION,
+        info: *mut LEAP_CONNECTION_INFO
+    ) -> eLeapRS;
+    fn LeapSetPolicyFlags(connection: LEAP_CONNECTION, set: u64, clear: u64) -> eLeapRS;
+    fn LeapSetPolicyFlagsEx(
+        connection: LEAP_CONNECTION,
+        device: LEAP_DEVICE,
+        set: u64,
+        clear: u64
+    ) -> eLeapRS;
+    fn LeapSetTrackingMode(connection: LEAP_CONNECTION, mode: eLeapTrackingMode) -> eLeapRS;
+    fn LeapSetTrackingModeEx(
+        connection: LEAP_CONNECTION,
+        device: LEAP_DEVICE,
+        mode: eLeapTrackingMode
+    ) -> eLeapRS;
+    fn LeapGetTrackingMode(connection: LEAP_CONNECTION) -> eLeapRS;
+    fn LeapOpenDevice(device_ref: LEAP_DEVICE_REF, device: *mut LEAP_DEVICE) -> eLeapRS;
+    fn LeapSubscribeEvents(connection: LEAP_CONNECTION, device: LEAP_DEVICE) -> eLeapRS;
+    fn LeapUnsubscribeEvents(connection: LEAP_CONNECTION, device: LEAP_DEVICE) -> eLeapRS;
+    fn LeapGetDeviceInfo(device: LEAP_DEVICE, info: *mut LEAP_DEVICE_INFO) -> eLeapRS;
+    fn LeapCloseDevice(device: LEAP_DEVICE);
+    fn LeapCloseConnection(connection: LEAP_CONNECTION);
+    fn LeapDestroyConnection(connection: LEAP_CONNECTION);
+    fn LeapGetVersion(
+        connection: LEAP_CONNECTION,
+        version_part: eLeapVersionPart,
+        version: *mut LEAP_VERSION
+    ) -> eLeapRS;
+}
+
+/// Environment variable with the path of the library or of its directory.
+#[cfg(feature = "dynamic-loading")]
+const LIBRARY_PATH_VARIABLE: &str = "LEAPC_LIBRARY_PATH";
+
+/// Where the tracking software installs the library.
+#[cfg(all(feature = "dynamic-loading", target_os = "macos"))]
+const DEFAULT_DIRS: &[&str] = &["/Applications/Ultraleap Hand Tracking.app/Contents/LeapSDK/lib"];
+#[cfg(all(feature = "dynamic-loading", target_os = "windows"))]
+const DEFAULT_DIRS: &[&str] = &["C:\\Program Files\\Ultraleap\\LeapSDK\\lib\\x64"];
+#[cfg(all(feature = "dynamic-loading", target_os = "linux"))]
+const DEFAULT_DIRS: &[&str] = &["/usr/lib/ultraleap-hand-tracking-service"];
+#[cfg(all(
+    feature = "dynamic-loading",
+    not(any(target_os = "macos", target_os = "windows", target_os = "linux"))
+))]
+const DEFAULT_DIRS: &[&str] = &[];
+
+#[cfg(feature = "dynamic-loading")]
+struct LoadedLibrary {
+    functions: functions::Functions,
+    // the functions point into the library, it must stay loaded
+    _library: libloading::Library,
+}
+
+#[cfg(feature = "dynamic-loading")]
+static LIBRARY: OnceLock<LoadedLibrary> = OnceLock::new();
+#[cfg(feature = "dynamic-loading")]
+static LOADING: Mutex<()> = Mutex::new(());
+
+#[cfg(feature = "dynamic-loading")]
+fn loaded_functions() -> &'static functions::Functions {
+    // every caller is behind a successful `load`
+    &LIBRARY.get().expect("LeapC is not loaded").functions
+}
+
+/// Loads LeapC unless it is loaded already, trying `LEAPC_LIBRARY_PATH`, then
+/// `search_paths` in order, then the install location of the tracking
+/// software and finally the search path of the system.
+///
+/// Each path is either the library itself or a directory containing it.
+/// Controllers load the library on their own, calling this is only needed
+/// for additional search paths.
+#[cfg(feature = "dynamic-loading")]
+pub fn load_library<P: AsRef<Path>>(search_paths: &[P]) -> Result<(), LeapError> {
+    let _loading = LOADING.lock().unwrap();
+    if LIBRARY.get().is_some() {
+        return Ok(());
+    }
+
+    let library_name = libloading::library_filename("LeapC");
+    let mut candidates: Vec<PathBuf> = std::env::var_os(LIBRARY_PATH_VARIABLE)
+        .map(PathBuf::from)
+        .into_iter()
+        .chain(search_paths.iter().map(|path| path.as_ref().to_path_buf()))
+        .chain(DEFAULT_DIRS.iter().map(PathBuf::from))
+        .map(|path| {
+            if path.is_dir() {
+                path.join(&library_name)
+            } else {
+                path
+            }
+        })
+        .collect();
+    candidates.push(PathBuf::from(&library_name));
+
+    for candidate in &candidates {
+        let loaded = unsafe {
+            libloading::Library::new(candidate).and_then(|library| {
+                Ok(LoadedLibrary {
+                    functions: functions::Functions::load(&library)?,
+                    _library: library,
+                })
+            })
+        };
+        match loaded {
+            Ok(loaded_library) => {
+                info!("loaded LeapC from {}", candidate.display());
+                let _ = LIBRARY.set(loaded_library);
+                return Ok(());
+            }
+            // most candidates are expected to be missing
+            Err(error) => debug!("cannot load {}, error: {}", candidate.display(), error),
+        }
+    }
+    warn!(
+        "cannot load LeapC from any of {}",
+        candidates
+            .iter()
+            .map(|candidate| candidate.display().to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+    Err(LeapError::LibraryNotFound)
+}
+
+/// Makes sure LeapC can be called.
+pub(crate) fn load() -> Result<(), LeapError> {
+    #[cfg(feature = "dynamic-loading")]
+    return load_library::<&Path>(&[]);
+    #[cfg(not(feature = "dynamic-loading"))]
+    Ok(())
+}
+
+/// Whether LeapC can be called, always when it is linked.
+pub(crate) fn is_loaded() -> bool {
+    #[cfg(feature = "dynamic-loading")]
+    return LIBRARY.get().is_some();
+    #[cfg(not(feature = "dynamic-loading"))]
+    true
+}
@@ -1,9 +1,72 @@
+// LeapC constants and functions are used under their C names
 #![allow(non_upper_case_globals)]
-#![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
-#![allow(dead_code)]
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+// bindgen enum types are `u32` or `i32` depending on the platform
+#![allow(clippy::unnecessary_cast)]
 
+// with `dynamic-loading` the bindings have no functions to call
+#[cfg(all(feature = "raw", feature = "dynamic-loading"))]
+compile_error!(
+    "the `raw` functions need the linked LeapC, `raw` cannot be combined with `dynamic-loading`"
+);
+
+#[cfg(feature = "raw")]
+pub use ultraleap_sys as raw;
+use ultraleap_sys::*;
+
+mod connection_status;
+pub use connection_status::ConnectionStatus;
+mod controller_config;
+pub use controller_config::ControllerConfig;
+mod device_info;
+pub use device_info::DeviceInfo;
+mod event_hub;
+mod event_queue;
+pub use event_queue::DeliveryPolicy;
+mod event_receiver;
+pub use event_receiver::{EventReceiver, Frames};
+#[cfg(feature = "async")]
+mod event_stream;
+#[cfg(feature = "async")]
+pub use event_stream::{EventStream, TrackingStream};
+#[cfg(feature = "glam")]
+mod glam_conversions;
+mod hand_tracking_source;
+pub use hand_tracking_source::HandTrackingSource;
 mod leap_controller;
 pub use leap_controller::LeapController;
+mod leap_error;
+pub use leap_error::LeapError;
+mod leap_event;
+pub use leap_event::*;
+mod leapc;
+#[cfg(feature = "dynamic-loading")]
+pub use leapc::load_library;
+#[cfg(feature = "mint")]
+mod mint_conversions;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions;
+mod playback_controller;
+pub use playback_controller::{PlaybackController, PlaybackSpeed};
+mod policy_flags;
+pub use policy_flags::PolicyFlags;
+mod quaternion;
+pub use quaternion::Quaternion;
+mod recorder;
+pub use recorder::Recorder;
+pub mod recording;
+pub use recording::RecordingHeader;
+mod scripted_source;
+pub use scripted_source::ScriptedSource;
+mod subscription;
+pub use subscription::Subscription;
+mod synthetic_hand;
+pub use synthetic_hand::{HandAnimation, HandPose};
 mod tracking_event;
+pub use tracking_event::{Bone, Digit, Hand, Handedness, InteractionBox, Palm, TrackingEvent};
+mod tracking_mode;
+pub use tracking_mode::TrackingMode;
+mod vector3;
+pub use vector3::Vector3;
+mod version;
+pub use version::Version;
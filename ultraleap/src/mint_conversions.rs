@@ -0,0 +1,52 @@
+use crate::{Quaternion, Vector3};
+
+impl From<Vector3> for mint::Vector3<f32> {
+    fn from(vector: Vector3) -> mint::Vector3<f32> {
+        mint::Vector3 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+impl From<mint::Vector3<f32>> for Vector3 {
+    fn from(vector: mint::Vector3<f32>) -> Vector3 {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for mint::Point3<f32> {
+    fn from(vector: Vector3) -> mint::Point3<f32> {
+        mint::Point3 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+impl From<mint::Point3<f32>> for Vector3 {
+    fn from(point: mint::Point3<f32>) -> Vector3 {
+        Vector3::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<Quaternion> for mint::Quaternion<f32> {
+    fn from(quaternion: Quaternion) -> mint::Quaternion<f32> {
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: quaternion.x,
+                y: quaternion.y,
+                z: quaternion.z,
+            },
+            s: quaternion.w,
+        }
+    }
+}
+
+impl From<mint::Quaternion<f32>> for Quaternion {
+    fn from(quaternion: mint::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(quaternion.v.x, quaternion.v.y, quaternion.v.z, quaternion.s)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Bone, Palm, Quaternion, Vector3};
+use nalgebra::{Isometry3, Point3, Translation3, UnitQuaternion};
+
+impl From<Vector3> for nalgebra::Vector3<f32> {
+    fn from(vector: Vector3) -> nalgebra::Vector3<f32> {
+        nalgebra::Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<nalgebra::Vector3<f32>> for Vector3 {
+    fn from(vector: nalgebra::Vector3<f32>) -> Vector3 {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3> for Point3<f32> {
+    fn from(vector: Vector3) -> Point3<f32> {
+        Point3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Point3<f32>> for Vector3 {
+    fn from(point: Point3<f32>) -> Vector3 {
+        Vector3::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<Quaternion> for nalgebra::Quaternion<f32> {
+    fn from(quaternion: Quaternion) -> nalgebra::Quaternion<f32> {
+        nalgebra::Quaternion::new(quaternion.w, quaternion.x, quaternion.y, quaternion.z)
+    }
+}
+
+impl From<nalgebra::Quaternion<f32>> for Quaternion {
+    fn from(quaternion: nalgebra::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(quaternion.i, quaternion.j, quaternion.k, quaternion.w)
+    }
+}
+
+impl From<Quaternion> for UnitQuaternion<f32> {
+    fn from(quaternion: Quaternion) -> UnitQuaternion<f32> {
+        UnitQuaternion::new_normalize(quaternion.into())
+    }
+}
+
+impl From<UnitQuaternion<f32>> for Quaternion {
+    fn from(quaternion: UnitQuaternion<f32>) -> Quaternion {
+        quaternion.into_inner().into()
+    }
+}
+
+/// Pose of the bone, placed at its `prev_joint`.
+impl From<&Bone> for Isometry3<f32> {
+    fn from(bone: &Bone) -> Isometry3<f32> {
+        Isometry3::from_parts(
+            Translation3::new(bone.prev_joint.x, bone.prev_joint.y, bone.prev_joint.z),
+            bone.rotation.into(),
+        )
+    }
+}
+
+/// Pose of the palm, placed at its `position`.
+impl From<&Palm> for Isometry3<f32> {
+    fn from(palm: &Palm) -> Isometry3<f32> {
+        Isometry3::from_parts(
+            Translation3::new(palm.position.x, palm.position.y, palm.position.z),
+            palm.orientation.into(),
+        )
+    }
+}
@@ -0,0 +1,566 @@
+use crate::{
+    event_hub::EventHub, event_queue::*, event_receiver::*, recording::*, subscription::*,
+    ConnectionStatus, DeviceEvent, DeviceInfo, DeviceStatusChangeEvent, HandTrackingSource,
+    LeapEvent, TrackingEvent,
+};
+use log::{error, info};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How fast a recording is played back.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum PlaybackSpeed {
+    /// With the timing of the recording.
+    #[default]
+    Original,
+    /// The timing of the recording sped up by the given factor, below 1 slows it down.
+    Factor(f32),
+    /// Every event right after the previous one, see [`DeliveryPolicy`] to
+    /// not pile up frames.
+    AsFastAsPossible,
+}
+
+struct PlaybackState {
+    /// Index of the next record to play.
+    next_record: usize,
+    /// Playback position at `anchor_instant`.
+    anchor_position: Duration,
+    anchor_instant: Instant,
+    speed: PlaybackSpeed,
+    paused: bool,
+    looping: bool,
+    connection_lost: bool,
+    finished: bool,
+    stopped: bool,
+}
+
+impl PlaybackState {
+    fn position(&self, now: Instant) -> Duration {
+        if self.paused {
+            return self.anchor_position;
+        }
+        let played = now - self.anchor_instant;
+        match self.speed {
+            PlaybackSpeed::Original | PlaybackSpeed::AsFastAsPossible => {
+                self.anchor_position + played
+            }
+            PlaybackSpeed::Factor(factor) => self.anchor_position + played.mul_f32(factor.max(0.0)),
+        }
+    }
+
+    /// Restarts the clock at `position`.
+    fn anchor(&mut self, position: Duration) {
+        self.anchor_position = position;
+        self.anchor_instant = Instant::now();
+    }
+}
+
+/// State shared with the playback thread.
+struct Playback {
+    header: RecordingHeader,
+    records: Vec<TimedRecord>,
+    state: Mutex<PlaybackState>,
+    changed: Condvar,
+    devices: Mutex<Vec<DeviceInfo>>,
+}
+
+impl Playback {
+    /// The devices attached at the start plus the changes of the records
+    /// before `next_record`.
+    fn devices_before(&self, next_record: usize) -> Vec<DeviceInfo> {
+        let mut devices = self.header.devices.clone();
+        for timed_record in &self.records[..next_record] {
+            match &timed_record.record {
+                Record::DeviceAttached(device_info) => {
+                    devices.retain(|attached| attached.id != device_info.id);
+                    devices.push(device_info.clone());
+                }
+                Record::DeviceLost(device_id) => devices.retain(|device| device.id != *device_id),
+                Record::DeviceStatusChange(status_change) => {
+                    set_device_status(&mut devices, status_change)
+                }
+                _ => {}
+            }
+        }
+        devices
+    }
+
+    /// Moves the playback to `next_record`, with the devices and connection
+    /// state left by the records before it.
+    fn restore(&self, state: &mut PlaybackState, next_record: usize) {
+        state.next_record = next_record;
+        state.connection_lost = self.records[..next_record]
+            .iter()
+            .rev()
+            .find_map(|timed_record| match timed_record.record {
+                Record::ConnectionLost => Some(true),
+                Record::Connection(_) => Some(false),
+                _ => None,
+            })
+            .unwrap_or_default();
+        *self.devices.lock().unwrap() = self.devices_before(next_record);
+    }
+
+    fn duration(&self) -> Duration {
+        self.records
+            .last()
+            .map(|timed_record| timed_record.elapsed)
+            .unwrap_or_default()
+    }
+}
+
+/// Plays back a recording of a [`Recorder`](crate::Recorder) as a
+/// [`HandTrackingSource`], whose callbacks are invoked from the playback
+/// thread.
+///
+/// The playback starts when the recording is opened and ends after the last
+/// event, closing the pending events like a closed connection, or starts over
+/// when looping.
+pub struct PlaybackController {
+    playback: Arc<Playback>,
+    playback_thread: Option<thread::JoinHandle<()>>,
+    events: EventHub,
+}
+
+impl PlaybackController {
+    /// Opens the recording at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<PlaybackController> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads the whole recording from `reader`.
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<PlaybackController> {
+        let (header, records) = read_recording(reader)?;
+        info!(
+            "playing back recording with {} records, client version {}",
+            records.len(),
+            header.client_version
+        );
+        let playback = Arc::new(Playback {
+            devices: Mutex::new(header.devices.clone()),
+            header,
+            records,
+            state: Mutex::new(PlaybackState {
+                next_record: 0,
+                anchor_position: Duration::ZERO,
+                anchor_instant: Instant::now(),
+                speed: PlaybackSpeed::default(),
+                paused: false,
+                looping: false,
+                connection_lost: false,
+                finished: false,
+                stopped: false,
+            }),
+            changed: Condvar::new(),
+        });
+        let events = EventHub::new();
+        let playback_thread = {
+            let playback = playback.clone();
+            let callbacks = events.callbacks().clone();
+            let broadcast = events.broadcast().clone();
+            thread::spawn(move || play(&playback, &callbacks, &broadcast))
+        };
+        Ok(PlaybackController {
+            playback,
+            playback_thread: Some(playback_thread),
+            events,
+        })
+    }
+
+    /// Header of the recording.
+    pub fn header(&self) -> &RecordingHeader {
+        &self.playback.header
+    }
+
+    /// Time from the start of the recording to its last event.
+    pub fn duration(&self) -> Duration {
+        self.playback.duration()
+    }
+
+    /// Current time in the recording.
+    pub fn position(&self) -> Duration {
+        let state = self.playback.state.lock().unwrap();
+        state.position(Instant::now()).min(self.duration())
+    }
+
+    /// Continues the playback at `position`, events before it are skipped.
+    /// Has no effect once the playback is finished.
+    pub fn seek(&self, position: Duration) {
+        let mut state = self.playback.state.lock().unwrap();
+        let next_record = self
+            .playback
+            .records
+            .partition_point(|timed_record| timed_record.elapsed < position);
+        self.playback.restore(&mut state, next_record);
+        state.anchor(position);
+        self.playback.changed.notify_all();
+    }
+
+    pub fn set_speed(&self, speed: PlaybackSpeed) {
+        let mut state = self.playback.state.lock().unwrap();
+        let position = state.position(Instant::now());
+        state.speed = speed;
+        state.anchor(position);
+        self.playback.changed.notify_all();
+    }
+
+    /// Whether the playback starts over after the last event, a recording
+    /// with all events at its start is only played once. Devices that differ
+    /// between the end and the start are reported lost or attached again.
+    pub fn set_looping(&self, looping: bool) {
+        self.playback.state.lock().unwrap().looping = looping;
+        self.playback.changed.notify_all();
+    }
+
+    pub fn pause(&self) {
+        let mut state = self.playback.state.lock().unwrap();
+        if !state.paused {
+            let position = state.position(Instant::now());
+            state.anchor(position);
+            state.paused = true;
+        }
+    }
+
+    pub fn resume(&self) {
+        let mut state = self.playback.state.lock().unwrap();
+        if state.paused {
+            let position = state.anchor_position;
+            state.anchor(position);
+            state.paused = false;
+            self.playback.changed.notify_all();
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.playback.state.lock().unwrap().paused
+    }
+
+    /// Whether all events were played and the playback is not looping.
+    pub fn is_finished(&self) -> bool {
+        self.playback.state.lock().unwrap().finished
+    }
+
+    /// `Connected` while playing back, `Lost` from a recorded connection loss
+    /// until the recorded reconnection
+    /// and `NotConnected` once finished.
+    pub fn connection_status(&self) -> ConnectionStatus {
+        let state = self.playback.state.lock().unwrap();
+        if state.finished {
+            ConnectionStatus::NotConnected
+        } else if state.connection_lost {
+            ConnectionStatus::Lost
+        } else {
+            ConnectionStatus::Connected
+        }
+    }
+
+    /// The devices attached at the current position of the recording.
+    pub fn devices(&self) -> Vec<DeviceInfo> {
+        self.playback.devices.lock().unwrap().clone()
+    }
+}
+
+impl Drop for PlaybackController {
+    fn drop(&mut self) {
+        self.playback.state.lock().unwrap().stopped = true;
+        self.playback.changed.notify_all();
+        if let Some(playback_thread) = self.playback_thread.take() {
+            if playback_thread.join().is_err() {
+                error!("playback thread panicked");
+            }
+        }
+    }
+}
+
+/// Body of the playback thread, hands out the records when they are due.
+fn play(playback: &Playback, callbacks: &Callbacks, broadcast: &EventBroadcast) {
+    info!("start playback thread");
+    let _close_on_drop = CloseOnDrop(broadcast);
+    let mut state = playback.state.lock().unwrap();
+    while !state.stopped {
+        if state.paused {
+            state = playback.changed.wait(state).unwrap();
+            continue;
+        }
+        let timed_record = match playback.records.get(state.next_record) {
+            Some(timed_record) => timed_record,
+            // a recording without length would be repeated without pause
+            None if state.looping && playback.duration() > Duration::ZERO => {
+                let devices_at_end = playback.devices.lock().unwrap().clone();
+                playback.restore(&mut state, 0);
+                state.anchor(Duration::ZERO);
+                let devices_at_start = playback.devices.lock().unwrap().clone();
+                drop(state);
+                announce_device_changes(&devices_at_end, &devices_at_start, callbacks, broadcast);
+                state = playback.state.lock().unwrap();
+                continue;
+            }
+            None => {
+                state.finished = true;
+                break;
+            }
+        };
+        let now = Instant::now();
+        if state.speed == PlaybackSpeed::AsFastAsPossible {
+            state.anchor(timed_record.elapsed);
+        } else {
+            let position = state.position(now);
+            if position < timed_record.elapsed {
+                let wait = match state.speed {
+                    PlaybackSpeed::Factor(factor) if factor > 0.0 => {
+                        (timed_record.elapsed - position).div_f32(factor)
+                    }
+                    PlaybackSpeed::Factor(_) => Duration::from_millis(100),
+                    _ => timed_record.elapsed - position,
+                };
+                state = playback.changed.wait_timeout(state, wait).unwrap().0;
+                continue;
+            }
+        }
+        state.next_record += 1;
+        match timed_record.record {
+            Record::ConnectionLost => state.connection_lost = true,
+            Record::Connection(_) => state.connection_lost = false,
+            _ => {}
+        }
+        // consumers may control the playback from callbacks and while the
+        // event is delivered
+        drop(state);
+        let event = match &timed_record.record {
+            Record::Tracking(tracking_event) => {
+                callbacks.tracking.invoke(tracking_event);
+                LeapEvent::Tracking(tracking_event.clone())
+            }
+            Record::DeviceAttached(device_info) => {
+                {
+                    let mut devices = playback.devices.lock().unwrap();
+                    devices.retain(|attached| attached.id != device_info.id);
+                    devices.push(device_info.clone());
+                }
+                callbacks.device_attached.invoke(device_info);
+                LeapEvent::Device(DeviceEvent {
+                    device_id: device_info.id,
+                    status: device_info.status,
+                })
+            }
+            Record::DeviceLost(device_id) => {
+                let lost_device = {
+                    let mut devices = playback.devices.lock().unwrap();
+                    devices
+                        .iter()
+                        .position(|device| device.id == *device_id)
+                        .map(|position| devices.remove(position))
+                };
+                if let Some(device_info) = lost_device {
+                    callbacks.device_lost.invoke(&device_info);
+                }
+                LeapEvent::DeviceLost(DeviceEvent {
+                    device_id: *device_id,
+                    status: 0,
+                })
+            }
+            Record::ConnectionLost => {
+                callbacks.connection_lost.invoke(&());
+                LeapEvent::ConnectionLost
+            }
+            Record::Connection(connection_event) => LeapEvent::Connection(*connection_event),
+            Record::DeviceFailure(failure) => LeapEvent::DeviceFailure(*failure),
+            Record::DeviceStatusChange(status_change) => {
+                set_device_status(&mut playback.devices.lock().unwrap(), status_change);
+                LeapEvent::DeviceStatusChange(*status_change)
+            }
+        };
+        broadcast.push(event);
+        state = playback.state.lock().unwrap();
+    }
+    drop(state);
+    info!("end playback thread")
+}
+
+/// Hands out the device changes of a jump in the recording as device lost and
+/// attached events.
+fn announce_device_changes(
+    previous_devices: &[DeviceInfo],
+    devices: &[DeviceInfo],
+    callbacks: &Callbacks,
+    broadcast: &EventBroadcast,
+) {
+    for device_info in previous_devices {
+        if !devices.iter().any(|device| device.id == device_info.id) {
+            callbacks.device_lost.invoke(device_info);
+            broadcast.push(LeapEvent::DeviceLost(DeviceEvent {
+                device_id: device_info.id,
+                status: 0,
+            }));
+        }
+    }
+    for device_info in devices {
+        if !previous_devices.contains(device_info) {
+            callbacks.device_attached.invoke(device_info);
+            broadcast.push(LeapEvent::Device(DeviceEvent {
+                device_id: device_info.id,
+                status: device_info.status,
+            }));
+        }
+    }
+}
+
+fn set_device_status(devices: &mut [DeviceInfo], status_change: &DeviceStatusChangeEvent) {
+    if let Some(device_info) = devices
+        .iter_mut()
+        .find(|device_info| device_info.id == status_change.device_id)
+    {
+        device_info.status = status_change.status;
+    }
+}
+
+impl HandTrackingSource for PlaybackController {
+    fn receiver(&self) -> &EventReceiver {
+        self.events.receiver()
+    }
+
+    fn subscribe(&self, policy: DeliveryPolicy) -> EventReceiver {
+        self.events.subscribe(policy)
+    }
+
+    fn on_tracking(&self, callback: Box<dyn FnMut(&TrackingEvent) + Send>) -> Subscription {
+        self.events.on_tracking(callback)
+    }
+
+    fn on_device_attached(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        self.events.on_device_attached(callback)
+    }
+
+    fn on_device_lost(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        self.events.on_device_lost(callback)
+    }
+
+    fn on_connection_lost(&self, callback: Box<dyn FnMut() + Send>) -> Subscription {
+        self.events.on_connection_lost(callback)
+    }
+
+    fn devices(&self) -> Vec<DeviceInfo> {
+        PlaybackController::devices(self)
+    }
+
+    fn connection_status(&self) -> ConnectionStatus {
+        PlaybackController::connection_status(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionEvent, HandAnimation, Handedness};
+
+    fn device_info(id: u32) -> DeviceInfo {
+        DeviceInfo {
+            id,
+            serial: format!("LP{}", id),
+            pid: 0,
+            h_fov: 0.0,
+            v_fov: 0.0,
+            range: 0,
+            baseline: 0,
+            caps: 0,
+            status: 0,
+        }
+    }
+
+    #[test]
+    fn seek_restores_the_connection_state() {
+        let frames = HandAnimation::open_fist_pinch(Handedness::Right).frames(1.0);
+        let mut record_writer = RecordWriter::new(vec![], &RecordingHeader::default()).unwrap();
+        record_writer
+            .write_tracking(Duration::ZERO, &frames[0])
+            .unwrap();
+        record_writer
+            .write_connection_lost(Duration::from_millis(1))
+            .unwrap();
+        record_writer
+            .write_connection(Duration::from_secs(2), &ConnectionEvent { flags: 0 })
+            .unwrap();
+        record_writer
+            .write_tracking(Duration::from_secs(10), &frames[1])
+            .unwrap();
+        let recording = record_writer.into_inner();
+
+        let playback_controller = PlaybackController::from_reader(&recording[..]).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while playback_controller.connection_status() != ConnectionStatus::Lost {
+            assert!(Instant::now() < deadline, "connection loss not played back");
+            thread::sleep(Duration::from_millis(1));
+        }
+        playback_controller.pause();
+        for (position, connection_status) in [
+            (Duration::from_secs(3), ConnectionStatus::Connected),
+            (Duration::from_secs(1), ConnectionStatus::Lost),
+            (Duration::ZERO, ConnectionStatus::Connected),
+        ] {
+            playback_controller.seek(position);
+            assert_eq!(playback_controller.connection_status(), connection_status);
+        }
+    }
+
+    #[test]
+    fn looping_announces_the_devices_of_the_start() {
+        let frames = HandAnimation::open_fist_pinch(Handedness::Right).frames(1.0);
+        let header = RecordingHeader {
+            devices: vec![device_info(1)],
+            ..RecordingHeader::default()
+        };
+        let mut record_writer = RecordWriter::new(vec![], &header).unwrap();
+        record_writer.write_device_lost(Duration::ZERO, 1).unwrap();
+        record_writer
+            .write_device_attached(Duration::ZERO, &device_info(2))
+            .unwrap();
+        record_writer
+            .write_tracking(Duration::from_millis(200), &frames[0])
+            .unwrap();
+        let recording = record_writer.into_inner();
+
+        let playback_controller = PlaybackController::from_reader(&recording[..]).unwrap();
+        playback_controller.set_looping(true);
+        let receiver = playback_controller.subscribe(DeliveryPolicy::Unbounded);
+        // the events of the restart follow the first tracking event
+        let mut restart = vec![];
+        let mut tracked = false;
+        while restart.len() < 2 {
+            let event = receiver
+                .wait_event(Duration::from_secs(5))
+                .expect("restart not played back");
+            match event {
+                LeapEvent::Tracking(_) => tracked = true,
+                LeapEvent::Device(device_event) if tracked => {
+                    restart.push((true, device_event.device_id))
+                }
+                LeapEvent::DeviceLost(device_event) if tracked => {
+                    restart.push((false, device_event.device_id))
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(restart, [(false, 2), (true, 1)]);
+    }
+
+    #[test]
+    fn panicking_callback_ends_the_playback() {
+        let frames = HandAnimation::open_fist_pinch(Handedness::Right).frames(1.0);
+        let mut record_writer = RecordWriter::new(vec![], &RecordingHeader::default()).unwrap();
+        record_writer
+            .write_tracking(Duration::from_millis(100), &frames[0])
+            .unwrap();
+        let recording = record_writer.into_inner();
+
+        let mut playback_controller = PlaybackController::from_reader(&recording[..]).unwrap();
+        let subscription =
+            playback_controller.on_tracking(Box::new(|_| panic!("callback failed on purpose")));
+        assert_eq!(playback_controller.frames().count(), 0);
+        drop(subscription);
+        drop(playback_controller);
+    }
+}
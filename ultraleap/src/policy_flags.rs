@@ -0,0 +1,27 @@
+use crate::*;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `eLeapPolicyFlag` flags requesting service behaviour for this client.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct PolicyFlags: u32 {
+        /// Keep receiving frames while the application is not focused.
+        const BACKGROUND_FRAMES = _eLeapPolicyFlag_eLeapPolicyFlag_BackgroundFrames as u32;
+        /// Stream the camera images.
+        const IMAGES = _eLeapPolicyFlag_eLeapPolicyFlag_Images as u32;
+        /// Optimize tracking for a device mounted on a head mounted display.
+        const OPTIMIZE_HMD = _eLeapPolicyFlag_eLeapPolicyFlag_OptimizeHMD as u32;
+        /// Allow pausing and resuming the device.
+        const ALLOW_PAUSE_RESUME = _eLeapPolicyFlag_eLeapPolicyFlag_AllowPauseResume as u32;
+        /// Allow point mapping.
+        const MAP_POINTS = _eLeapPolicyFlag_eLeapPolicyFlag_MapPoints as u32;
+        /// Optimize tracking for a device mounted above a screen, facing the user.
+        const OPTIMIZE_SCREEN_TOP = _eLeapPolicyFlag_eLeapPolicyFlag_OptimizeScreenTop as u32;
+    }
+}
+
+impl PolicyFlags {
+    pub(crate) fn from_raw(raw_policy: u32) -> PolicyFlags {
+        PolicyFlags::from_bits_retain(raw_policy)
+    }
+}
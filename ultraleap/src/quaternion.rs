@@ -0,0 +1,262 @@
+use crate::{Vector3, _LEAP_QUATERNION};
+use std::ops::{Mul, Neg};
+
+/// Rotation as unit quaternion, the components are in LeapC's `x, y, z, w` order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion { x, y, z, w }
+    }
+
+    pub(crate) fn from_raw(raw_quaternion: &_LEAP_QUATERNION) -> Quaternion {
+        unsafe { Quaternion::from_array(raw_quaternion.__bindgen_anon_1.v) }
+    }
+
+    pub const fn from_array(array: [f32; 4]) -> Quaternion {
+        Quaternion::new(array[0], array[1], array[2], array[3])
+    }
+
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Rotation of `angle` radians around the unit vector `axis`.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Quaternion {
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        let axis = axis * sin;
+        Quaternion::new(axis.x, axis.y, axis.z, cos)
+    }
+
+    /// Rotation of a row major rotation matrix, `matrix[row][column]`.
+    pub fn from_rotation_matrix(matrix: [[f32; 3]; 3]) -> Quaternion {
+        let [[m00, m01, m02], [m10, m11, m12], [m20, m21, m22]] = matrix;
+        let trace = m00 + m11 + m22;
+        // computed from the largest component for numerical stability
+        let quaternion = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, s / 4.0)
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            Quaternion::new(s / 4.0, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            Quaternion::new((m01 + m10) / s, s / 4.0, (m12 + m21) / s, (m02 - m20) / s)
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            Quaternion::new((m02 + m20) / s, (m12 + m21) / s, s / 4.0, (m10 - m01) / s)
+        };
+        quaternion.normalize()
+    }
+
+    /// Row major rotation matrix, `matrix[row][column]`.
+    pub fn to_rotation_matrix(self) -> [[f32; 3]; 3] {
+        let Quaternion { x, y, z, w } = self;
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]
+    }
+
+    fn vector(self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(self, other: Quaternion) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Quaternion of length 1, the zero quaternion becomes the identity.
+    pub fn normalize(self) -> Quaternion {
+        let length = self.length();
+        if length > 0.0 {
+            Quaternion::new(
+                self.x / length,
+                self.y / length,
+                self.z / length,
+                self.w / length,
+            )
+        } else {
+            Quaternion::IDENTITY
+        }
+    }
+
+    /// The opposite rotation of a unit quaternion.
+    pub fn conjugate(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Rotates `vector` by this unit quaternion.
+    pub fn rotate(self, vector: Vector3) -> Vector3 {
+        let t = self.vector().cross(vector) * 2.0;
+        vector + t * self.w + self.vector().cross(t)
+    }
+
+    /// Normalized linear interpolation, `t` of 0 gives `self` and 1 gives `other`.
+    pub fn lerp(self, other: Quaternion, t: f32) -> Quaternion {
+        // interpolate along the shorter arc
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        Quaternion::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+            self.w + (other.w - self.w) * t,
+        )
+        .normalize()
+    }
+
+    /// Spherical linear interpolation with constant angular speed, `t` of 0
+    /// gives `self` and 1 gives `other`.
+    pub fn slerp(self, other: Quaternion, t: f32) -> Quaternion {
+        let mut dot = self.dot(other);
+        let mut other = other;
+        if dot < 0.0 {
+            other = -other;
+            dot = -dot;
+        }
+        // nearly parallel, the spherical formula would divide by almost zero
+        if dot > 0.9995 {
+            return self.lerp(other, t);
+        }
+        let angle = dot.acos();
+        let sin = angle.sin();
+        let self_factor = ((1.0 - t) * angle).sin() / sin;
+        let other_factor = (t * angle).sin() / sin;
+        Quaternion::new(
+            self.x * self_factor + other.x * other_factor,
+            self.y * self_factor + other.y * other_factor,
+            self.z * self_factor + other.z * other_factor,
+            self.w * self_factor + other.w * other_factor,
+        )
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Quaternion::IDENTITY
+    }
+}
+
+impl From<[f32; 4]> for Quaternion {
+    fn from(array: [f32; 4]) -> Quaternion {
+        Quaternion::from_array(array)
+    }
+}
+
+impl From<Quaternion> for [f32; 4] {
+    fn from(quaternion: Quaternion) -> [f32; 4] {
+        quaternion.to_array()
+    }
+}
+
+/// Combined rotation, first rotating by `other` then by `self`.
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, vector: Vector3) -> Vector3 {
+        self.rotate(vector)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn assert_same_rotation(actual: Quaternion, expected: Quaternion) {
+        // `q` and `-q` are the same rotation
+        assert!(
+            actual.dot(expected).abs() > 0.9999,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn rotation_matrix_round_trip() {
+        let matrix = Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_2).to_rotation_matrix();
+        let expected = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        for (row, expected_row) in matrix.iter().zip(expected) {
+            for (value, expected_value) in row.iter().zip(expected_row) {
+                assert!((value - expected_value).abs() < 1e-6, "{:?}", matrix);
+            }
+        }
+
+        // one rotation for every branch of the conversion
+        for quaternion in [
+            Quaternion::IDENTITY,
+            Quaternion::from_axis_angle(Vector3::X, PI),
+            Quaternion::from_axis_angle(Vector3::Y, PI),
+            Quaternion::from_axis_angle(Vector3::Z, PI),
+            Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), 2.0),
+        ] {
+            let round_trip = Quaternion::from_rotation_matrix(quaternion.to_rotation_matrix());
+            assert_same_rotation(round_trip, quaternion);
+        }
+    }
+
+    #[test]
+    fn slerp_interpolates_the_angle() {
+        let end = Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_2);
+        assert_same_rotation(Quaternion::IDENTITY.slerp(end, 0.0), Quaternion::IDENTITY);
+        assert_same_rotation(Quaternion::IDENTITY.slerp(end, 1.0), end);
+        assert_same_rotation(
+            Quaternion::IDENTITY.slerp(end, 0.5),
+            Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_4),
+        );
+    }
+
+    #[test]
+    fn rotate_quarter_turn_around_z() {
+        let rotated = Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_2).rotate(Vector3::X);
+        assert!(rotated.distance(Vector3::Y) < 1e-6, "{:?}", rotated);
+    }
+}
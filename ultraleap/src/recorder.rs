@@ -0,0 +1,155 @@
+use crate::recording::*;
+use crate::{leapc, DeliveryPolicy, LeapController, LeapError, LeapEvent};
+use log::{error, info};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, Sender, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Records the tracking, device and connection events of a controller, see
+/// the [`recording`](crate::recording) module for the format.
+///
+/// Events are written by a background thread until the recorder is stopped
+/// or dropped, or the connection of the controller is closed.
+pub struct Recorder {
+    stop_sender: Option<Sender<bool>>,
+    writing_thread: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl Recorder {
+    /// Starts recording into a new file at `path`.
+    pub fn create<P: AsRef<Path>>(
+        leap_controller: &LeapController,
+        path: P,
+    ) -> io::Result<Recorder> {
+        ensure_loaded()?;
+        let file = File::create(path)?;
+        Self::start(leap_controller, BufWriter::new(file))
+    }
+
+    /// Starts recording into `writer`, which is flushed after every event.
+    /// Fails with a `NotFound` error wrapping [`LeapError::LibraryNotFound`]
+    /// when LeapC could not be loaded.
+    pub fn start<W>(leap_controller: &LeapController, writer: W) -> io::Result<Recorder>
+    where
+        W: Write + Send + 'static,
+    {
+        ensure_loaded()?;
+        let header = RecordingHeader {
+            format_version: FORMAT_VERSION,
+            client_version: leap_controller.client_version().unwrap_or_default(),
+            start_time_us: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|since_epoch| since_epoch.as_micros() as u64)
+                .unwrap_or_default(),
+            devices: leap_controller.devices(),
+        };
+        let mut record_writer = RecordWriter::new(writer, &header)?;
+        let start = Instant::now();
+        let receiver = leap_controller.subscribe(DeliveryPolicy::Unbounded);
+        let device_lookup = leap_controller.device_lookup();
+
+        let (stop_sender, stop_receiver) = mpsc::channel();
+        let writing_thread = thread::spawn(move || {
+            info!("start recording");
+            let result = loop {
+                match stop_receiver.try_recv() {
+                    Err(TryRecvError::Empty) => {}
+                    _ => break Ok(()),
+                }
+                // stamped by the polling thread, so a backlog of the writer
+                // does not shift the records
+                let (polled, event) = match receiver.wait_timed_event(Duration::from_millis(100)) {
+                    Some(timed_event) => timed_event,
+                    None if receiver.is_finished() => break Ok(()),
+                    None => continue,
+                };
+                let elapsed = polled.saturating_duration_since(start);
+                let written = match event {
+                    LeapEvent::Tracking(tracking_event) => {
+                        record_writer.write_tracking(elapsed, &tracking_event)
+                    }
+                    LeapEvent::Device(device_event) => {
+                        match device_lookup(device_event.device_id) {
+                            Some(device_info) => {
+                                record_writer.write_device_attached(elapsed, &device_info)
+                            }
+                            // lost again before it could be recorded
+                            None => Ok(()),
+                        }
+                    }
+                    LeapEvent::DeviceLost(device_event) => {
+                        record_writer.write_device_lost(elapsed, device_event.device_id)
+                    }
+                    LeapEvent::Connection(connection_event) => {
+                        record_writer.write_connection(elapsed, &connection_event)
+                    }
+                    LeapEvent::ConnectionLost => record_writer.write_connection_lost(elapsed),
+                    LeapEvent::DeviceFailure(failure) => {
+                        record_writer.write_device_failure(elapsed, &failure)
+                    }
+                    LeapEvent::DeviceStatusChange(status_change) => {
+                        record_writer.write_device_status_change(elapsed, &status_change)
+                    }
+                    _ => Ok(()),
+                };
+                if let Err(error) = written {
+                    error!("failed to write recording, error: {}", error);
+                    break Err(error);
+                }
+            };
+            info!("end recording");
+            result
+        });
+
+        Ok(Recorder {
+            stop_sender: Some(stop_sender),
+            writing_thread: Some(writing_thread),
+        })
+    }
+
+    /// Stops recording, returning the first error that ended the recording early.
+    pub fn stop(mut self) -> io::Result<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if let Some(stop_sender) = self.stop_sender.take() {
+            // the thread is already gone when the recording failed
+            let _ = stop_sender.send(true);
+        }
+        match self
+            .writing_thread
+            .take()
+            .map(|writing_thread| writing_thread.join())
+        {
+            Some(Ok(result)) => result,
+            Some(Err(_)) => {
+                error!("recording thread panicked");
+                Err(io::Error::other("recording thread panicked"))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// A controller without LeapC has nothing to record.
+fn ensure_loaded() -> io::Result<()> {
+    if leapc::is_loaded() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            LeapError::LibraryNotFound,
+        ))
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        // errors are already logged by the writing thread
+        let _ = self.finish();
+    }
+}
@@ -0,0 +1,644 @@
+//! Binary format of recorded sessions, all numbers are little endian.
+//!
+//! A recording starts with a header:
+//!
+//! - magic `ULEAPREC`
+//! - format version `u16`
+//! - LeapC client library version, three `i32`
+//! - start of the recording in microseconds since the unix epoch `u64`
+//! - number of attached devices `u32`, followed by their [`DeviceInfo`]
+//!
+//! followed by records until the end of the file:
+//!
+//! - record kind `u8`
+//! - time since the start of the recording in microseconds `u64`
+//! - payload length in bytes `u32`, followed by the payload
+//!
+//! The record kinds and their payloads are:
+//!
+//! 1. tracking event, its hands and interaction box
+//! 2. device attached, its [`DeviceInfo`]
+//! 3. device lost, the device id `u32`
+//! 4. connection lost, no payload
+//! 5. device failure, the `eLeapDeviceStatus` failure code `u32`
+//! 6. device status change, the device id, last status and status `u32`
+//! 7. connection, the `eLeapServiceDisposition` flags `u32`
+//!
+//! All other events, like images, logs and policy or configuration changes,
+//! are not recorded.
+//!
+//! Records are stamped when their event is polled from the service and
+//! written in that order. Readers skip records of unknown kinds and end the
+//! recording at a record cut short by a crash.
+
+use crate::{
+    eLeapTrackingMode, Bone, ConnectionEvent, DeviceFailureEvent, DeviceInfo,
+    DeviceStatusChangeEvent, Digit, Hand, Handedness, InteractionBox, Palm, Quaternion,
+    TrackingEvent, TrackingMode, Vector3, Version,
+};
+use log::warn;
+use std::io::{self, Read, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub(crate) const MAGIC: &[u8; 8] = b"ULEAPREC";
+pub(crate) const FORMAT_VERSION: u16 = 1;
+
+pub(crate) const RECORD_TRACKING: u8 = 1;
+pub(crate) const RECORD_DEVICE_ATTACHED: u8 = 2;
+pub(crate) const RECORD_DEVICE_LOST: u8 = 3;
+pub(crate) const RECORD_CONNECTION_LOST: u8 = 4;
+pub(crate) const RECORD_DEVICE_FAILURE: u8 = 5;
+pub(crate) const RECORD_DEVICE_STATUS_CHANGE: u8 = 6;
+pub(crate) const RECORD_CONNECTION: u8 = 7;
+
+/// Header of a recording.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecordingHeader {
+    pub format_version: u16,
+    pub client_version: Version,
+    /// Start of the recording in microseconds since the unix epoch.
+    pub start_time_us: u64,
+    /// Devices attached when the recording started.
+    pub devices: Vec<DeviceInfo>,
+}
+
+/// A record of a known kind.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Record {
+    Tracking(Arc<TrackingEvent>),
+    DeviceAttached(DeviceInfo),
+    DeviceLost(u32),
+    ConnectionLost,
+    DeviceFailure(DeviceFailureEvent),
+    DeviceStatusChange(DeviceStatusChangeEvent),
+    Connection(ConnectionEvent),
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct TimedRecord {
+    /// Time since the start of the recording.
+    pub elapsed: Duration,
+    pub record: Record,
+}
+
+/// Writes the header and records of a recording.
+pub(crate) struct RecordWriter<W: Write> {
+    writer: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> RecordWriter<W> {
+    pub fn new(mut writer: W, header: &RecordingHeader) -> io::Result<RecordWriter<W>> {
+        let mut buffer = vec![];
+        buffer.extend_from_slice(MAGIC);
+        put_u16(&mut buffer, header.format_version);
+        put_i32(&mut buffer, header.client_version.major);
+        put_i32(&mut buffer, header.client_version.minor);
+        put_i32(&mut buffer, header.client_version.patch);
+        put_u64(&mut buffer, header.start_time_us);
+        put_u32(&mut buffer, header.devices.len() as u32);
+        for device_info in &header.devices {
+            put_device_info(&mut buffer, device_info);
+        }
+        writer.write_all(&buffer)?;
+        writer.flush()?;
+        buffer.clear();
+        Ok(RecordWriter { writer, buffer })
+    }
+
+    pub fn write_tracking(&mut self, elapsed: Duration, event: &TrackingEvent) -> io::Result<()> {
+        put_tracking_event(&mut self.buffer, event);
+        self.write_record(RECORD_TRACKING, elapsed)
+    }
+
+    pub fn write_device_attached(
+        &mut self,
+        elapsed: Duration,
+        device_info: &DeviceInfo,
+    ) -> io::Result<()> {
+        put_device_info(&mut self.buffer, device_info);
+        self.write_record(RECORD_DEVICE_ATTACHED, elapsed)
+    }
+
+    pub fn write_device_lost(&mut self, elapsed: Duration, device_id: u32) -> io::Result<()> {
+        put_u32(&mut self.buffer, device_id);
+        self.write_record(RECORD_DEVICE_LOST, elapsed)
+    }
+
+    pub fn write_connection_lost(&mut self, elapsed: Duration) -> io::Result<()> {
+        self.write_record(RECORD_CONNECTION_LOST, elapsed)
+    }
+
+    pub fn write_device_failure(
+        &mut self,
+        elapsed: Duration,
+        failure: &DeviceFailureEvent,
+    ) -> io::Result<()> {
+        put_u32(&mut self.buffer, failure.status);
+        self.write_record(RECORD_DEVICE_FAILURE, elapsed)
+    }
+
+    pub fn write_device_status_change(
+        &mut self,
+        elapsed: Duration,
+        status_change: &DeviceStatusChangeEvent,
+    ) -> io::Result<()> {
+        put_u32(&mut self.buffer, status_change.device_id);
+        put_u32(&mut self.buffer, status_change.last_status);
+        put_u32(&mut self.buffer, status_change.status);
+        self.write_record(RECORD_DEVICE_STATUS_CHANGE, elapsed)
+    }
+
+    pub fn write_connection(
+        &mut self,
+        elapsed: Duration,
+        connection_event: &ConnectionEvent,
+    ) -> io::Result<()> {
+        put_u32(&mut self.buffer, connection_event.flags);
+        self.write_record(RECORD_CONNECTION, elapsed)
+    }
+
+    #[cfg(test)]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes the payload collected in the buffer as one record and flushes
+    /// it, so a crash loses at most the record being written.
+    fn write_record(&mut self, kind: u8, elapsed: Duration) -> io::Result<()> {
+        let mut record_header = Vec::with_capacity(13);
+        record_header.push(kind);
+        put_u64(&mut record_header, elapsed.as_micros() as u64);
+        put_u32(&mut record_header, self.buffer.len() as u32);
+        let result = self
+            .writer
+            .write_all(&record_header)
+            .and_then(|_| self.writer.write_all(&self.buffer))
+            .and_then(|_| self.writer.flush());
+        self.buffer.clear();
+        result
+    }
+}
+
+fn put_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_i32(buffer: &mut Vec<u8>, value: i32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u64(buffer: &mut Vec<u8>, value: u64) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_i64(buffer: &mut Vec<u8>, value: i64) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_f32(buffer: &mut Vec<u8>, value: f32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_string(buffer: &mut Vec<u8>, value: &str) {
+    put_u32(buffer, value.len() as u32);
+    buffer.extend_from_slice(value.as_bytes());
+}
+
+fn put_vector(buffer: &mut Vec<u8>, vector: Vector3) {
+    put_f32(buffer, vector.x);
+    put_f32(buffer, vector.y);
+    put_f32(buffer, vector.z);
+}
+
+fn put_quaternion(buffer: &mut Vec<u8>, quaternion: Quaternion) {
+    put_f32(buffer, quaternion.x);
+    put_f32(buffer, quaternion.y);
+    put_f32(buffer, quaternion.z);
+    put_f32(buffer, quaternion.w);
+}
+
+fn put_device_info(buffer: &mut Vec<u8>, device_info: &DeviceInfo) {
+    put_u32(buffer, device_info.id);
+    put_string(buffer, &device_info.serial);
+    put_u32(buffer, device_info.pid);
+    put_f32(buffer, device_info.h_fov);
+    put_f32(buffer, device_info.v_fov);
+    put_u32(buffer, device_info.range);
+    put_u32(buffer, device_info.baseline);
+    put_u32(buffer, device_info.caps);
+    put_u32(buffer, device_info.status);
+}
+
+fn put_bone(buffer: &mut Vec<u8>, bone: &Bone) {
+    put_vector(buffer, bone.prev_joint);
+    put_vector(buffer, bone.next_joint);
+    put_f32(buffer, bone.width);
+    put_quaternion(buffer, bone.rotation);
+}
+
+fn put_digit(buffer: &mut Vec<u8>, digit: &Digit) {
+    put_i32(buffer, digit.finger_id);
+    put_bone(buffer, &digit.metacarpal);
+    put_bone(buffer, &digit.proximal);
+    put_bone(buffer, &digit.intermediate);
+    put_bone(buffer, &digit.distal);
+    put_u32(buffer, digit.is_extended);
+}
+
+fn put_palm(buffer: &mut Vec<u8>, palm: &Palm) {
+    put_vector(buffer, palm.position);
+    put_vector(buffer, palm.stabilized_position);
+    put_vector(buffer, palm.velocity);
+    put_vector(buffer, palm.normal);
+    put_vector(buffer, palm.direction);
+    put_f32(buffer, palm.width);
+    put_quaternion(buffer, palm.orientation);
+}
+
+fn put_hand(buffer: &mut Vec<u8>, hand: &Hand) {
+    put_u32(buffer, hand.id);
+    buffer.push(match hand.handedness {
+        Handedness::Left => 0,
+        Handedness::Right => 1,
+    });
+    put_f32(buffer, hand.confidence);
+    put_u64(buffer, hand.visible_time.as_micros() as u64);
+    put_f32(buffer, hand.pinch_distance);
+    put_f32(buffer, hand.grab_angle);
+    put_f32(buffer, hand.pinch_strength);
+    put_f32(buffer, hand.grab_strength);
+    put_palm(buffer, &hand.palm);
+    for digit in [
+        &hand.thumb,
+        &hand.index,
+        &hand.middle,
+        &hand.ring,
+        &hand.pinky,
+    ] {
+        put_digit(buffer, digit);
+    }
+    put_bone(buffer, &hand.arm);
+}
+
+fn put_interaction_box(buffer: &mut Vec<u8>, interaction_box: &InteractionBox) {
+    put_f32(buffer, interaction_box.width);
+    put_f32(buffer, interaction_box.height);
+    put_f32(buffer, interaction_box.depth);
+    put_u32(buffer, interaction_box.tracking_mode.to_raw() as u32);
+}
+
+fn put_tracking_event(buffer: &mut Vec<u8>, event: &TrackingEvent) {
+    put_i64(buffer, event.event_id);
+    put_i64(buffer, event.frame_id);
+    put_i64(buffer, event.timestamp_us);
+    put_f32(buffer, event.framerate);
+    put_u32(buffer, event.device_id);
+    put_interaction_box(buffer, &event.interaction_box);
+    put_u32(buffer, event.hands.len() as u32);
+    for hand in &event.hands {
+        put_hand(buffer, hand);
+    }
+}
+
+/// Reads a whole recording, skipping records of unknown kinds.
+pub(crate) fn read_recording<R: Read>(
+    mut reader: R,
+) -> io::Result<(RecordingHeader, Vec<TimedRecord>)> {
+    let mut data = vec![];
+    reader.read_to_end(&mut data)?;
+    let mut source = Source(&data);
+
+    if source.take(MAGIC.len())? != MAGIC {
+        return Err(invalid_data("not an ultraleap recording".to_string()));
+    }
+    let format_version = source.u16()?;
+    if format_version > FORMAT_VERSION {
+        return Err(invalid_data(format!(
+            "unsupported recording format version {}",
+            format_version
+        )));
+    }
+    let client_version = Version {
+        major: source.i32()?,
+        minor: source.i32()?,
+        patch: source.i32()?,
+    };
+    let start_time_us = source.u64()?;
+    let device_count = source.u32()?;
+    let devices = (0..device_count)
+        .map(|_| source.device_info())
+        .collect::<io::Result<Vec<DeviceInfo>>>()?;
+    let header = RecordingHeader {
+        format_version,
+        client_version,
+        start_time_us,
+        devices,
+    };
+
+    let mut records = vec![];
+    while !source.0.is_empty() {
+        match source.record() {
+            Ok(Some(record)) => records.push(record),
+            Ok(None) => {}
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {
+                warn!("recording ends with an incomplete record");
+                break;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    Ok((header, records))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Reads values from the front of a byte slice.
+struct Source<'a>(&'a [u8]);
+
+impl<'a> Source<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn i32(&mut self) -> io::Result<i32> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn i64(&mut self) -> io::Result<i64> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+
+    fn f32(&mut self) -> io::Result<f32> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    fn vector(&mut self) -> io::Result<Vector3> {
+        Ok(Vector3::new(self.f32()?, self.f32()?, self.f32()?))
+    }
+
+    fn quaternion(&mut self) -> io::Result<Quaternion> {
+        Ok(Quaternion::new(
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+        ))
+    }
+
+    fn device_info(&mut self) -> io::Result<DeviceInfo> {
+        Ok(DeviceInfo {
+            id: self.u32()?,
+            serial: self.string()?,
+            pid: self.u32()?,
+            h_fov: self.f32()?,
+            v_fov: self.f32()?,
+            range: self.u32()?,
+            baseline: self.u32()?,
+            caps: self.u32()?,
+            status: self.u32()?,
+        })
+    }
+
+    fn bone(&mut self) -> io::Result<Bone> {
+        Ok(Bone {
+            prev_joint: self.vector()?,
+            next_joint: self.vector()?,
+            width: self.f32()?,
+            rotation: self.quaternion()?,
+        })
+    }
+
+    fn digit(&mut self) -> io::Result<Digit> {
+        Ok(Digit {
+            finger_id: self.i32()?,
+            metacarpal: self.bone()?,
+            proximal: self.bone()?,
+            intermediate: self.bone()?,
+            distal: self.bone()?,
+            is_extended: self.u32()?,
+        })
+    }
+
+    fn palm(&mut self) -> io::Result<Palm> {
+        Ok(Palm {
+            position: self.vector()?,
+            stabilized_position: self.vector()?,
+            velocity: self.vector()?,
+            normal: self.vector()?,
+            direction: self.vector()?,
+            width: self.f32()?,
+            orientation: self.quaternion()?,
+        })
+    }
+
+    fn hand(&mut self) -> io::Result<Hand> {
+        Ok(Hand {
+            id: self.u32()?,
+            handedness: match self.u8()? {
+                0 => Handedness::Left,
+                _ => Handedness::Right,
+            },
+            confidence: self.f32()?,
+            visible_time: Duration::from_micros(self.u64()?),
+            pinch_distance: self.f32()?,
+            grab_angle: self.f32()?,
+            pinch_strength: self.f32()?,
+            grab_strength: self.f32()?,
+            palm: self.palm()?,
+            thumb: self.digit()?,
+            index: self.digit()?,
+            middle: self.digit()?,
+            ring: self.digit()?,
+            pinky: self.digit()?,
+            arm: self.bone()?,
+        })
+    }
+
+    fn interaction_box(&mut self) -> io::Result<InteractionBox> {
+        Ok(InteractionBox {
+            width: self.f32()?,
+            height: self.f32()?,
+            depth: self.f32()?,
+            tracking_mode: TrackingMode::from_raw(self.u32()? as eLeapTrackingMode),
+        })
+    }
+
+    fn tracking_event(&mut self) -> io::Result<TrackingEvent> {
+        let mut tracking_event = TrackingEvent {
+            event_id: self.i64()?,
+            frame_id: self.i64()?,
+            timestamp_us: self.i64()?,
+            framerate: self.f32()?,
+            device_id: self.u32()?,
+            hands: vec![],
+            interaction_box: self.interaction_box()?,
+        };
+        let hand_count = self.u32()?;
+        for _ in 0..hand_count {
+            tracking_event.hands.push(self.hand()?);
+        }
+        Ok(tracking_event)
+    }
+
+    /// The next record, `None` for a record of an unknown kind.
+    fn record(&mut self) -> io::Result<Option<TimedRecord>> {
+        let kind = self.u8()?;
+        let elapsed = Duration::from_micros(self.u64()?);
+        let len = self.u32()? as usize;
+        let mut payload = Source(self.take(len)?);
+        let record = payload
+            .payload(kind)
+            .map_err(|_| invalid_data(format!("malformed record of kind {}", kind)))?;
+        Ok(record.map(|record| TimedRecord { elapsed, record }))
+    }
+
+    fn payload(&mut self, kind: u8) -> io::Result<Option<Record>> {
+        Ok(Some(match kind {
+            RECORD_TRACKING => Record::Tracking(Arc::new(self.tracking_event()?)),
+            RECORD_DEVICE_ATTACHED => Record::DeviceAttached(self.device_info()?),
+            RECORD_DEVICE_LOST => Record::DeviceLost(self.u32()?),
+            RECORD_CONNECTION_LOST => Record::ConnectionLost,
+            RECORD_DEVICE_FAILURE => Record::DeviceFailure(DeviceFailureEvent {
+                status: self.u32()?,
+            }),
+            RECORD_DEVICE_STATUS_CHANGE => Record::DeviceStatusChange(DeviceStatusChangeEvent {
+                device_id: self.u32()?,
+                last_status: self.u32()?,
+                status: self.u32()?,
+            }),
+            RECORD_CONNECTION => Record::Connection(ConnectionEvent { flags: self.u32()? }),
+            _ => return Ok(None),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HandAnimation;
+
+    #[test]
+    fn records_are_read_back_as_written() {
+        let device_info = DeviceInfo {
+            id: 3,
+            serial: "LP0123".to_string(),
+            pid: 4,
+            h_fov: 2.5,
+            v_fov: 2.0,
+            range: 800,
+            baseline: 40,
+            caps: 1,
+            status: 2,
+        };
+        let header = RecordingHeader {
+            format_version: FORMAT_VERSION,
+            client_version: Version {
+                major: 5,
+                minor: 12,
+                patch: 1,
+            },
+            start_time_us: 1_700_000_000_000_000,
+            devices: vec![device_info.clone()],
+        };
+        let mut records = HandAnimation::open_fist_pinch(Handedness::Left)
+            .frames(10.0)
+            .into_iter()
+            .map(|mut tracking_event| {
+                // the format keeps whole microseconds
+                for hand in &mut tracking_event.hands {
+                    hand.visible_time = Duration::from_micros(hand.visible_time.as_micros() as u64);
+                }
+                Record::Tracking(Arc::new(tracking_event))
+            })
+            .collect::<Vec<Record>>();
+        records.extend([
+            Record::DeviceAttached(device_info),
+            Record::DeviceStatusChange(DeviceStatusChangeEvent {
+                device_id: 3,
+                last_status: 2,
+                status: 6,
+            }),
+            Record::DeviceFailure(DeviceFailureEvent { status: 0xE8010000 }),
+            Record::DeviceLost(3),
+            Record::ConnectionLost,
+            Record::Connection(ConnectionEvent { flags: 1 }),
+        ]);
+        let timed_records = records
+            .into_iter()
+            .enumerate()
+            .map(|(index, record)| TimedRecord {
+                elapsed: Duration::from_millis(index as u64 * 20),
+                record,
+            })
+            .collect::<Vec<TimedRecord>>();
+
+        let mut record_writer = RecordWriter::new(vec![], &header).unwrap();
+        for timed_record in &timed_records {
+            let elapsed = timed_record.elapsed;
+            match &timed_record.record {
+                Record::Tracking(tracking_event) => {
+                    record_writer.write_tracking(elapsed, tracking_event)
+                }
+                Record::DeviceAttached(device_info) => {
+                    record_writer.write_device_attached(elapsed, device_info)
+                }
+                Record::DeviceLost(device_id) => {
+                    record_writer.write_device_lost(elapsed, *device_id)
+                }
+                Record::ConnectionLost => record_writer.write_connection_lost(elapsed),
+                Record::DeviceFailure(failure) => {
+                    record_writer.write_device_failure(elapsed, failure)
+                }
+                Record::DeviceStatusChange(status_change) => {
+                    record_writer.write_device_status_change(elapsed, status_change)
+                }
+                Record::Connection(connection_event) => {
+                    record_writer.write_connection(elapsed, connection_event)
+                }
+            }
+            .unwrap();
+        }
+
+        let (read_header, read_records) = read_recording(&record_writer.into_inner()[..]).unwrap();
+        assert_eq!(read_header, header);
+        assert_eq!(read_records, timed_records);
+    }
+}
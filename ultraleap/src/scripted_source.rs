@@ -0,0 +1,124 @@
+use crate::{
+    event_hub::EventHub, ConnectionStatus, DeliveryPolicy, DeviceInfo, EventReceiver,
+    HandTrackingSource, LeapEvent, Subscription, TrackingEvent,
+};
+use std::sync::Arc;
+
+/// [`HandTrackingSource`] handing out events given in advance, for tests and
+/// running applications without a device.
+pub struct ScriptedSource {
+    events: EventHub,
+    devices: Vec<DeviceInfo>,
+    connection_status: ConnectionStatus,
+}
+
+impl Default for ScriptedSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptedSource {
+    /// A connected source without devices and events.
+    pub fn new() -> ScriptedSource {
+        ScriptedSource {
+            events: EventHub::new(),
+            devices: vec![],
+            connection_status: ConnectionStatus::Connected,
+        }
+    }
+
+    /// A connected source handing out `frames` in order.
+    pub fn from_frames<I>(frames: I) -> ScriptedSource
+    where
+        I: IntoIterator<Item = TrackingEvent>,
+    {
+        let scripted_source = Self::new();
+        for frame in frames {
+            scripted_source.push_frame(frame);
+        }
+        scripted_source
+    }
+
+    /// Queues an event, subject to the delivery policy like a device's events,
+    /// and invokes the callbacks for it from the calling thread. Device
+    /// callbacks are only invoked for the devices set with
+    /// [`ScriptedSource::set_devices`].
+    pub fn push_event(&self, event: LeapEvent) {
+        let callbacks = self.events.callbacks();
+        match &event {
+            LeapEvent::Tracking(tracking_event) => callbacks.tracking.invoke(tracking_event),
+            LeapEvent::Device(device_event) => {
+                if let Some(device_info) = self.device(device_event.device_id) {
+                    callbacks.device_attached.invoke(device_info);
+                }
+            }
+            LeapEvent::DeviceLost(device_event) => {
+                if let Some(device_info) = self.device(device_event.device_id) {
+                    callbacks.device_lost.invoke(device_info);
+                }
+            }
+            LeapEvent::ConnectionLost => callbacks.connection_lost.invoke(&()),
+            _ => {}
+        }
+        self.events.broadcast().push(event);
+    }
+
+    pub fn push_frame(&self, frame: TrackingEvent) {
+        self.push_event(LeapEvent::Tracking(Arc::new(frame)));
+    }
+
+    /// Ends the events like a closed connection, waiting for events returns
+    /// right away once the pending events are taken.
+    pub fn finish(&self) {
+        self.events.broadcast().close();
+    }
+
+    pub fn set_devices(&mut self, devices: Vec<DeviceInfo>) {
+        self.devices = devices;
+    }
+
+    pub fn set_connection_status(&mut self, connection_status: ConnectionStatus) {
+        self.connection_status = connection_status;
+    }
+
+    fn device(&self, device_id: u32) -> Option<&DeviceInfo> {
+        self.devices
+            .iter()
+            .find(|device_info| device_info.id == device_id)
+    }
+}
+
+impl HandTrackingSource for ScriptedSource {
+    fn receiver(&self) -> &EventReceiver {
+        self.events.receiver()
+    }
+
+    fn subscribe(&self, policy: DeliveryPolicy) -> EventReceiver {
+        self.events.subscribe(policy)
+    }
+
+    fn on_tracking(&self, callback: Box<dyn FnMut(&TrackingEvent) + Send>) -> Subscription {
+        self.events.on_tracking(callback)
+    }
+
+    fn on_device_attached(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        self.events.on_device_attached(callback)
+    }
+
+    fn on_device_lost(&self, callback: Box<dyn FnMut(&DeviceInfo) + Send>) -> Subscription {
+        self.events.on_device_lost(callback)
+    }
+
+    fn on_connection_lost(&self, callback: Box<dyn FnMut() + Send>) -> Subscription {
+        self.events.on_connection_lost(callback)
+    }
+
+    fn devices(&self) -> Vec<DeviceInfo> {
+        self.devices.clone()
+    }
+
+    fn connection_status(&self) -> ConnectionStatus {
+        self.connection_status
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{tracking_event::TrackingEvent, DeviceInfo, PolicyFlags, TrackingMode};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+type Callback<T> = Box<dyn FnMut(&T) + Send>;
+
+/// Callbacks of one kind, invoked by the polling thread.
+pub(crate) struct CallbackList<T> {
+    callbacks: Mutex<Vec<(u64, Callback<T>)>>,
+}
+
+impl<T> CallbackList<T> {
+    fn new() -> CallbackList<T> {
+        CallbackList {
+            callbacks: Mutex::new(vec![]),
+        }
+    }
+
+    /// The callbacks, also after one of them panicked while being invoked.
+    fn lock(&self) -> MutexGuard<'_, Vec<(u64, Callback<T>)>> {
+        self.callbacks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn invoke(&self, value: &T) {
+        for (_, callback) in self.lock().iter_mut() {
+            callback(value);
+        }
+    }
+
+    fn add(&self, id: u64, callback: Callback<T>) {
+        self.lock().push((id, callback));
+    }
+
+    fn remove(&self, id: u64) {
+        self.lock().retain(|(callback_id, _)| *callback_id != id);
+    }
+}
+
+/// All callbacks registered on a controller.
+pub(crate) struct Callbacks {
+    next_id: AtomicU64,
+    pub tracking: CallbackList<TrackingEvent>,
+    pub device_attached: CallbackList<DeviceInfo>,
+    pub device_lost: CallbackList<DeviceInfo>,
+    pub connection_lost: CallbackList<()>,
+    pub policy_changed: CallbackList<PolicyFlags>,
+    pub tracking_mode_changed: CallbackList<TrackingMode>,
+}
+
+impl Callbacks {
+    pub fn new() -> Callbacks {
+        Callbacks {
+            next_id: AtomicU64::new(0),
+            tracking: CallbackList::new(),
+            device_attached: CallbackList::new(),
+            device_lost: CallbackList::new(),
+            connection_lost: CallbackList::new(),
+            policy_changed: CallbackList::new(),
+            tracking_mode_changed: CallbackList::new(),
+        }
+    }
+
+    /// Adds a callback to the list chosen by `list`, removing it again when
+    /// the returned subscription is dropped.
+    pub fn subscribe<T: 'static>(
+        callbacks: &Arc<Callbacks>,
+        list: fn(&Callbacks) -> &CallbackList<T>,
+        callback: Callback<T>,
+    ) -> Subscription {
+        let id = callbacks.next_id.fetch_add(1, Ordering::SeqCst);
+        list(callbacks).add(id, callback);
+        let callbacks = Arc::downgrade(callbacks);
+        Subscription {
+            unsubscribe: Some(Box::new(move || {
+                if let Some(callbacks) = callbacks.upgrade() {
+                    list(&callbacks).remove(id);
+                }
+            })),
+        }
+    }
+}
+
+/// Handle of a registered callback, the callback is unregistered when the
+/// handle is dropped. Dropping a handle from within a callback deadlocks.
+#[must_use = "the callback is unregistered when the subscription is dropped"]
+pub struct Subscription {
+    unsubscribe: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
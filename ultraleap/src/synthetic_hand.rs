@@ -0,0 +1,449 @@
+use crate::{
+    Bone, Digit, Hand, Handedness, InteractionBox, Palm, Quaternion, TrackingEvent, Vector3,
+};
+use std::f32::consts::PI;
+use std::time::Duration;
+
+// The skeleton of an average adult right hand in millimeters, relative to the
+// palm center with the fingers pointing towards -z and the palm facing -y.
+
+/// Carpometacarpal joints of the index, middle, ring and pinky finger.
+const FINGER_BASES: [Vector3; 4] = [
+    Vector3::new(-12.0, 0.0, 38.0),
+    Vector3::new(-2.0, 0.0, 40.0),
+    Vector3::new(9.0, 0.0, 38.0),
+    Vector3::new(19.0, 0.0, 34.0),
+];
+/// Knuckles of the index, middle, ring and pinky finger.
+const FINGER_KNUCKLES: [Vector3; 4] = [
+    Vector3::new(-27.0, 0.0, -28.0),
+    Vector3::new(-8.0, 0.0, -24.0),
+    Vector3::new(11.0, 0.0, -19.0),
+    Vector3::new(28.0, 0.0, -18.0),
+];
+/// Proximal, intermediate and distal phalanx lengths.
+const FINGER_PHALANGES: [[f32; 3]; 4] = [
+    [39.8, 22.4, 15.8],
+    [44.6, 26.3, 17.4],
+    [41.4, 25.7, 17.3],
+    [32.7, 18.1, 16.0],
+];
+const FINGER_WIDTHS: [f32; 4] = [18.0, 18.0, 17.0, 15.5];
+/// Sideways angle of the fingers when fully spread, positive towards the thumb.
+const FINGER_SPREADS: [f32; 4] = [0.26, 0.06, -0.12, -0.28];
+/// Bend of the three finger joints when fully curled.
+const FINGER_FLEXION: [f32; 3] = [1.5, 1.75, 1.2];
+
+/// The thumb has a zero length metacarpal like in the tracking data, its
+/// proximal bone is the anatomical metacarpal.
+const THUMB_BASE: Vector3 = Vector3::new(-22.0, -8.0, 30.0);
+const THUMB_PHALANGES: [f32; 3] = [46.0, 32.0, 25.0];
+const THUMB_WIDTH: f32 = 20.0;
+/// Bend of the two outer thumb joints when fully curled.
+const THUMB_FLEXION: [f32; 2] = [0.9, 1.1];
+
+const PALM_WIDTH: f32 = 85.0;
+const WRIST: Vector3 = Vector3::new(0.0, 0.0, 45.0);
+const ARM_LENGTH: f32 = 250.0;
+const ARM_WIDTH: f32 = 60.0;
+
+/// Distance of the thumb and index finger tips for a pinch strength of 1 and 0.
+const PINCH_TOUCHING: f32 = 20.0;
+const PINCH_APART: f32 = 100.0;
+
+/// Id of the hand in the frames of a [`HandAnimation`].
+const ANIMATED_HAND_ID: u32 = 1;
+
+/// High level description of a hand, turned into a [`Hand`] with a complete
+/// and anatomically consistent skeleton by [`HandPose::to_hand`].
+///
+/// Bone and palm rotations map -z onto the bone or palm direction and -y onto
+/// the side of the palm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandPose {
+    pub handedness: Handedness,
+    pub palm_position: Vector3,
+    /// Identity for a palm facing down with the fingers pointing away from
+    /// the user, as held over a desktop device.
+    pub palm_orientation: Quaternion,
+    /// Thumb, index, middle, ring and pinky curl, from 0 for straight to 1 for fully bent.
+    pub curls: [f32; 5],
+    /// Fanning of the fingers, from 0 for closed to 1 for fully spread.
+    pub spread: f32,
+    /// Scale of the hand, 1 is an average adult hand with a palm 85 mm wide.
+    pub size: f32,
+}
+
+impl HandPose {
+    /// Flat hand 200 mm above the device with slightly spread fingers.
+    pub fn open(handedness: Handedness) -> HandPose {
+        HandPose {
+            handedness,
+            palm_position: Vector3::new(0.0, 200.0, 0.0),
+            palm_orientation: Quaternion::IDENTITY,
+            curls: [0.0; 5],
+            spread: 0.5,
+            size: 1.0,
+        }
+    }
+
+    pub fn fist(handedness: Handedness) -> HandPose {
+        HandPose {
+            curls: [0.8, 1.0, 1.0, 1.0, 1.0],
+            spread: 0.0,
+            ..Self::open(handedness)
+        }
+    }
+
+    /// Thumb and index finger tips touching, the other fingers relaxed.
+    pub fn pinch(handedness: Handedness) -> HandPose {
+        HandPose {
+            curls: [0.5, 0.5, 0.2, 0.25, 0.3],
+            spread: 0.5,
+            ..Self::open(handedness)
+        }
+    }
+
+    /// Interpolates every parameter, `t` of 0 gives `self` and 1 gives `other`.
+    /// The handedness is always the one of `self`.
+    pub fn lerp(&self, other: &HandPose, t: f32) -> HandPose {
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+        HandPose {
+            handedness: self.handedness,
+            palm_position: self.palm_position.lerp(other.palm_position, t),
+            palm_orientation: self.palm_orientation.slerp(other.palm_orientation, t),
+            curls: std::array::from_fn(|i| lerp(self.curls[i], other.curls[i])),
+            spread: lerp(self.spread, other.spread),
+            size: lerp(self.size, other.size),
+        }
+    }
+
+    /// Builds the hand, fully confident and not moving.
+    pub fn to_hand(&self, id: u32) -> Hand {
+        let placement = Placement {
+            mirrored: self.handedness == Handedness::Left,
+            position: self.palm_position,
+            orientation: self.palm_orientation,
+            size: self.size,
+        };
+        let palm = Palm {
+            position: self.palm_position,
+            stabilized_position: self.palm_position,
+            velocity: Vector3::ZERO,
+            normal: self.palm_orientation.rotate(-Vector3::Y),
+            direction: self.palm_orientation.rotate(-Vector3::Z),
+            width: PALM_WIDTH * self.size,
+            orientation: self.palm_orientation,
+        };
+        let thumb = self.thumb(&placement);
+        let fingers: [Digit; 4] = std::array::from_fn(|i| self.finger(&placement, i));
+        let [index, middle, ring, pinky] = fingers;
+
+        let pinch_distance = thumb.distal.next_joint.distance(index.distal.next_joint);
+        let pinch_strength = (1.0
+            - (pinch_distance - PINCH_TOUCHING) / (PINCH_APART - PINCH_TOUCHING))
+            .clamp(0.0, 1.0);
+        // angle of the middle phalanges, the distal ones bend back past pi in a fist
+        let grab_angle = [&index, &middle, &ring, &pinky]
+            .iter()
+            .map(|finger| {
+                let finger_direction =
+                    (finger.intermediate.next_joint - finger.intermediate.prev_joint).normalize();
+                finger_direction.dot(palm.direction).clamp(-1.0, 1.0).acos()
+            })
+            .sum::<f32>()
+            / 4.0;
+
+        Hand {
+            id,
+            handedness: self.handedness,
+            confidence: 1.0,
+            visible_time: Duration::ZERO,
+            pinch_distance,
+            grab_angle,
+            pinch_strength,
+            grab_strength: grab_angle / PI,
+            palm,
+            thumb,
+            index,
+            middle,
+            ring,
+            pinky,
+            arm: placement.bone(
+                WRIST + Vector3::Z * ARM_LENGTH,
+                WRIST,
+                ARM_WIDTH,
+                Quaternion::IDENTITY,
+            ),
+        }
+    }
+
+    fn thumb(&self, placement: &Placement) -> Digit {
+        let curl = self.curls[0];
+        // swings in front of the palm and turns its pad towards the fingers
+        let rotation =
+            Quaternion::from_axis_angle(Vector3::Y, 0.6 + 0.3 * self.spread - 0.6 * curl)
+                * Quaternion::from_axis_angle(Vector3::X, -(0.15 + 0.5 * curl))
+                * Quaternion::from_axis_angle(Vector3::Z, 0.9);
+        let rotations = [
+            rotation,
+            rotation * flexion(THUMB_FLEXION[0] * curl),
+            rotation * flexion((THUMB_FLEXION[0] + THUMB_FLEXION[1]) * curl),
+        ];
+        let [proximal, intermediate, distal] =
+            placement.chain(THUMB_BASE, rotations, THUMB_PHALANGES, THUMB_WIDTH);
+        Digit {
+            finger_id: 0,
+            metacarpal: placement.bone(THUMB_BASE, THUMB_BASE, THUMB_WIDTH, rotation),
+            proximal,
+            intermediate,
+            distal,
+            is_extended: (curl < 0.5) as u32,
+        }
+    }
+
+    /// Index to pinky finger for `finger` 0 to 3.
+    fn finger(&self, placement: &Placement, finger: usize) -> Digit {
+        let curl = self.curls[finger + 1];
+        let (base, knuckle) = (FINGER_BASES[finger], FINGER_KNUCKLES[finger]);
+        let metacarpal_direction = (knuckle - base).normalize();
+        let metacarpal_rotation = Quaternion::from_axis_angle(
+            Vector3::Y,
+            f32::atan2(-metacarpal_direction.x, -metacarpal_direction.z),
+        );
+        let proximal_rotation =
+            Quaternion::from_axis_angle(Vector3::Y, FINGER_SPREADS[finger] * self.spread)
+                * flexion(FINGER_FLEXION[0] * curl);
+        let intermediate_rotation = proximal_rotation * flexion(FINGER_FLEXION[1] * curl);
+        let distal_rotation = intermediate_rotation * flexion(FINGER_FLEXION[2] * curl);
+        let width = FINGER_WIDTHS[finger];
+        let [proximal, intermediate, distal] = placement.chain(
+            knuckle,
+            [proximal_rotation, intermediate_rotation, distal_rotation],
+            FINGER_PHALANGES[finger],
+            width,
+        );
+        Digit {
+            finger_id: finger as i32 + 1,
+            metacarpal: placement.bone(base, knuckle, width, metacarpal_rotation),
+            proximal,
+            intermediate,
+            distal,
+            is_extended: (curl < 0.5) as u32,
+        }
+    }
+}
+
+/// Bend of a joint by `angle` radians towards the palm.
+fn flexion(angle: f32) -> Quaternion {
+    Quaternion::from_axis_angle(Vector3::X, -angle)
+}
+
+/// Moves the bones of the right hand skeleton to the pose of the palm.
+struct Placement {
+    /// Left hands are the right hand skeleton mirrored along x.
+    mirrored: bool,
+    position: Vector3,
+    orientation: Quaternion,
+    size: f32,
+}
+
+impl Placement {
+    fn point(&self, local_point: Vector3) -> Vector3 {
+        let mut point = local_point * self.size;
+        if self.mirrored {
+            point.x = -point.x;
+        }
+        self.position + self.orientation.rotate(point)
+    }
+
+    fn rotation(&self, local_rotation: Quaternion) -> Quaternion {
+        let rotation = if self.mirrored {
+            // the mirror image of a rotation turns the other way around y and z
+            Quaternion::new(
+                local_rotation.x,
+                -local_rotation.y,
+                -local_rotation.z,
+                local_rotation.w,
+            )
+        } else {
+            local_rotation
+        };
+        self.orientation * rotation
+    }
+
+    fn bone(
+        &self,
+        prev_joint: Vector3,
+        next_joint: Vector3,
+        width: f32,
+        rotation: Quaternion,
+    ) -> Bone {
+        Bone {
+            prev_joint: self.point(prev_joint),
+            next_joint: self.point(next_joint),
+            width: width * self.size,
+            rotation: self.rotation(rotation),
+        }
+    }
+
+    /// Bones joined end to end from `start`, each pointing along its rotation.
+    fn chain(
+        &self,
+        start: Vector3,
+        rotations: [Quaternion; 3],
+        lengths: [f32; 3],
+        width: f32,
+    ) -> [Bone; 3] {
+        let mut prev_joint = start;
+        std::array::from_fn(|i| {
+            let next_joint = prev_joint + rotations[i].rotate(-Vector3::Z) * lengths[i];
+            let bone = self.bone(prev_joint, next_joint, width, rotations[i]);
+            prev_joint = next_joint;
+            bone
+        })
+    }
+}
+
+/// Keyframed [`HandPose`]s interpolated into a sequence of tracking events,
+/// for deterministic tests and demos without a device.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HandAnimation {
+    /// Sorted by time.
+    keyframes: Vec<(Duration, HandPose)>,
+}
+
+impl HandAnimation {
+    pub fn new() -> HandAnimation {
+        Self::default()
+    }
+
+    /// Open palm, fist, pinch and open palm again, one second apart.
+    pub fn open_fist_pinch(handedness: Handedness) -> HandAnimation {
+        Self::new()
+            .keyframe(Duration::ZERO, HandPose::open(handedness))
+            .keyframe(Duration::from_secs(1), HandPose::fist(handedness))
+            .keyframe(Duration::from_secs(2), HandPose::pinch(handedness))
+            .keyframe(Duration::from_secs(3), HandPose::open(handedness))
+    }
+
+    /// Adds a pose reached at `time` after the start, replacing a keyframe at the same time.
+    pub fn keyframe(mut self, time: Duration, pose: HandPose) -> HandAnimation {
+        match self
+            .keyframes
+            .binary_search_by_key(&time, |(keyframe_time, _)| *keyframe_time)
+        {
+            Ok(i) => self.keyframes[i].1 = pose,
+            Err(i) => self.keyframes.insert(i, (time, pose)),
+        }
+        self
+    }
+
+    /// Time of the last keyframe.
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .last()
+            .map(|(time, _)| *time)
+            .unwrap_or_default()
+    }
+
+    /// Pose at `time` after the start, eased between the keyframes around it
+    /// and held before the first and after the last. `None` without keyframes.
+    pub fn pose_at(&self, time: Duration) -> Option<HandPose> {
+        let next = self
+            .keyframes
+            .partition_point(|(keyframe_time, _)| *keyframe_time <= time);
+        if next == 0 {
+            return self.keyframes.first().map(|(_, pose)| *pose);
+        }
+        let (prev_time, prev_pose) = self.keyframes[next - 1];
+        let Some((next_time, next_pose)) = self.keyframes.get(next) else {
+            return Some(prev_pose);
+        };
+        let t = (time - prev_time).as_secs_f32() / (*next_time - prev_time).as_secs_f32();
+        // smoothstep, the hand slows down towards every keyframe
+        Some(prev_pose.lerp(next_pose, t * t * (3.0 - 2.0 * t)))
+    }
+
+    /// Samples the animation at `framerate` frames per second from the start to
+    /// the last keyframe. Timestamps start at 0 and the palm velocity follows
+    /// the motion between the frames.
+    ///
+    /// # Panics
+    ///
+    /// If `framerate` is not a positive finite number.
+    pub fn frames(&self, framerate: f32) -> Vec<TrackingEvent> {
+        assert!(
+            framerate > 0.0 && framerate.is_finite(),
+            "invalid framerate {}",
+            framerate
+        );
+        if self.keyframes.is_empty() {
+            return vec![];
+        }
+        let frame_count = (self.duration().as_secs_f32() * framerate) as usize + 1;
+        let mut prev_position = None;
+        (0..frame_count)
+            .filter_map(|i| {
+                let time = Duration::from_secs_f32(i as f32 / framerate);
+                let mut hand = self.pose_at(time)?.to_hand(ANIMATED_HAND_ID);
+                hand.visible_time = time;
+                if let Some(prev_position) = prev_position {
+                    hand.palm.velocity = (hand.palm.position - prev_position) * framerate;
+                }
+                prev_position = Some(hand.palm.position);
+                Some(TrackingEvent {
+                    event_id: i as i64,
+                    frame_id: i as i64,
+                    timestamp_us: time.as_micros() as i64,
+                    framerate,
+                    device_id: 0,
+                    hands: vec![hand],
+                    interaction_box: InteractionBox::new(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinch_touches_thumb_and_index() {
+        for handedness in [Handedness::Left, Handedness::Right] {
+            let hand = HandPose::pinch(handedness).to_hand(1);
+            assert_eq!(hand.pinch_strength, 1.0);
+            assert!(hand.pinch_distance < 20.0, "{}", hand.pinch_distance);
+        }
+    }
+
+    #[test]
+    fn fist_grabs() {
+        for handedness in [Handedness::Left, Handedness::Right] {
+            let hand = HandPose::fist(handedness).to_hand(1);
+            assert!(hand.grab_strength > 0.9, "{}", hand.grab_strength);
+        }
+    }
+
+    #[test]
+    fn pose_is_held_outside_the_keyframes() {
+        let open = HandPose::open(Handedness::Right);
+        let fist = HandPose::fist(Handedness::Right);
+        let animation = HandAnimation::new()
+            .keyframe(Duration::from_secs(1), open)
+            .keyframe(Duration::from_secs(2), fist);
+        assert_eq!(animation.pose_at(Duration::ZERO), Some(open));
+        assert_eq!(animation.pose_at(Duration::from_secs(2)), Some(fist));
+        assert_eq!(animation.pose_at(Duration::from_secs(5)), Some(fist));
+        assert_eq!(HandAnimation::new().pose_at(Duration::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid framerate")]
+    fn frames_reject_a_zero_framerate() {
+        HandAnimation::open_fist_pinch(Handedness::Left).frames(0.0);
+    }
+}
@@ -1,28 +1,33 @@
-use crate::{_LEAP_BONE, _LEAP_DIGIT, _LEAP_HAND, _LEAP_PALM, _LEAP_TRACKING_EVENT};
-
-type LeapVector = [f32; 3];
-type LeapQuaternion = [f32; 4];
+use crate::{
+    _eLeapHandType_eLeapHandType_Left, eLeapHandType,
+    leapc::{self, LeapGetNow},
+    Quaternion, TrackingMode, Vector3, _LEAP_BONE, _LEAP_DIGIT, _LEAP_HAND, _LEAP_PALM,
+    _LEAP_TRACKING_EVENT,
+};
+use std::time::Duration;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bone {
-    pub prev_joint: LeapVector,
-    pub next_joint: LeapVector,
+    pub prev_joint: Vector3,
+    pub next_joint: Vector3,
     pub width: f32,
-    pub rotation: LeapQuaternion,
+    pub rotation: Quaternion,
 }
 
 impl Bone {
-    pub fn from_raw(raw_bone: &_LEAP_BONE) -> Bone {
-        unsafe {
-            Bone {
-                prev_joint: raw_bone.prev_joint.__bindgen_anon_1.v,
-                next_joint: raw_bone.next_joint.__bindgen_anon_1.v,
-                width: raw_bone.width,
-                rotation: raw_bone.rotation.__bindgen_anon_1.v,
-            }
+    pub(crate) fn from_raw(raw_bone: &_LEAP_BONE) -> Bone {
+        Bone {
+            prev_joint: Vector3::from_raw(&raw_bone.prev_joint),
+            next_joint: Vector3::from_raw(&raw_bone.next_joint),
+            width: raw_bone.width,
+            rotation: Quaternion::from_raw(&raw_bone.rotation),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Digit {
     pub finger_id: i32,
     // pub bones: [Bone; 4usize],
@@ -34,7 +39,7 @@ pub struct Digit {
 }
 
 impl Digit {
-    pub fn from_raw(raw_digit: &_LEAP_DIGIT) -> Digit {
+    pub(crate) fn from_raw(raw_digit: &_LEAP_DIGIT) -> Digit {
         unsafe {
             let digit = raw_digit.__bindgen_anon_1.__bindgen_anon_1;
             Digit {
@@ -49,24 +54,70 @@ impl Digit {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Palm {
-    pub position: LeapVector,
-    pub orientation: LeapQuaternion,
+    pub position: Vector3,
+    /// Position with a smoothing filter applied, better suited for pointing.
+    pub stabilized_position: Vector3,
+    /// Speed in millimeters per second.
+    pub velocity: Vector3,
+    /// Unit vector pointing out of the palm.
+    pub normal: Vector3,
+    /// Unit vector pointing from the palm towards the fingers.
+    pub direction: Vector3,
+    /// Width of the palm in millimeters.
+    pub width: f32,
+    pub orientation: Quaternion,
 }
 
 impl Palm {
-    pub fn from_raw(raw_palm: &_LEAP_PALM) -> Palm {
-        unsafe {
-            Palm {
-                position: raw_palm.position.__bindgen_anon_1.v,
-                orientation: raw_palm.orientation.__bindgen_anon_1.v,
-            }
+    pub(crate) fn from_raw(raw_palm: &_LEAP_PALM) -> Palm {
+        Palm {
+            position: Vector3::from_raw(&raw_palm.position),
+            stabilized_position: Vector3::from_raw(&raw_palm.stabilized_position),
+            velocity: Vector3::from_raw(&raw_palm.velocity),
+            normal: Vector3::from_raw(&raw_palm.normal),
+            direction: Vector3::from_raw(&raw_palm.direction),
+            width: raw_palm.width,
+            orientation: Quaternion::from_raw(&raw_palm.orientation),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+impl Handedness {
+    pub(crate) fn from_raw(raw_hand_type: eLeapHandType) -> Handedness {
+        match raw_hand_type {
+            _eLeapHandType_eLeapHandType_Left => Handedness::Left,
+            _ => Handedness::Right,
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hand {
     pub id: u32,
+    pub handedness: Handedness,
+    /// How certain the service is about the hand, from 0 to 1.
+    pub confidence: f32,
+    /// How long the hand has been tracked continuously.
+    pub visible_time: Duration,
+    /// Distance between the thumb and index finger tips in millimeters.
+    pub pinch_distance: f32,
+    /// Average angle of the fingers to the palm in radians, 0 when open, pi when fully curled.
+    pub grab_angle: f32,
+    /// From 0 when apart to 1 when the thumb and index finger tips touch.
+    pub pinch_strength: f32,
+    /// From 0 for an open hand to 1 for a fist.
+    pub grab_strength: f32,
     pub palm: Palm,
     // the fingers
     pub thumb: Digit,
@@ -74,23 +125,41 @@ pub struct Hand {
     pub middle: Digit,
     pub ring: Digit,
     pub pinky: Digit,
+    /// Forearm bone from the elbow (`prev_joint`) to the wrist (`next_joint`).
+    pub arm: Bone,
 }
 
 impl Hand {
-    pub fn from_raw(raw_hand: &_LEAP_HAND) -> Hand {
+    pub(crate) fn from_raw(raw_hand: &_LEAP_HAND) -> Hand {
         unsafe {
             let fingers = raw_hand.__bindgen_anon_1.__bindgen_anon_1;
             Hand {
                 id: raw_hand.id,
+                handedness: Handedness::from_raw(raw_hand.type_),
+                confidence: raw_hand.confidence,
+                visible_time: Duration::from_micros(raw_hand.visible_time),
+                pinch_distance: raw_hand.pinch_distance,
+                grab_angle: raw_hand.grab_angle,
+                pinch_strength: raw_hand.pinch_strength,
+                grab_strength: raw_hand.grab_strength,
                 palm: Palm::from_raw(&raw_hand.palm),
                 thumb: Digit::from_raw(&fingers.thumb),
                 index: Digit::from_raw(&fingers.index),
                 middle: Digit::from_raw(&fingers.middle),
                 ring: Digit::from_raw(&fingers.ring),
                 pinky: Digit::from_raw(&fingers.pinky),
+                arm: Bone::from_raw(&raw_hand.arm),
             }
         }
     }
+
+    pub fn wrist(&self) -> Vector3 {
+        self.arm.next_joint
+    }
+
+    pub fn elbow(&self) -> Vector3 {
+        self.arm.prev_joint
+    }
 }
 
 const Y_OFFSET: f32 = 120.0;
@@ -98,25 +167,46 @@ const HEIGHT: f32 = 300.0;
 const FOV_X: f32 = 130.0;
 const FOV_Y: f32 = 110.0;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InteractionBox {
     pub width: f32,
     pub height: f32,
     pub depth: f32,
+    /// Mounting of the device, decides the axes of the normalized points.
+    pub tracking_mode: TrackingMode,
 }
 
 impl InteractionBox {
     pub fn new() -> InteractionBox {
+        Self::for_mode(TrackingMode::Desktop)
+    }
+
+    pub fn for_mode(tracking_mode: TrackingMode) -> InteractionBox {
         InteractionBox {
             width: Y_OFFSET * f32::tan(FOV_X.to_radians() / 2.0) * 2.0,
             height: HEIGHT,
             depth: Y_OFFSET * f32::tan(FOV_Y.to_radians() / 2.0) * 2.0,
+            tracking_mode,
         }
     }
-    pub fn normalize_point(&self, point: LeapVector) -> LeapVector {
-        let normalized_x = point[0] / (self.width / 2.0);
-        let normalized_y = (point[1] - Y_OFFSET) / (self.height / 2.0);
-        let normalized_z = point[2] / (self.depth / 2.0);
-        [normalized_x, normalized_y - 1.0, normalized_z]
+
+    /// Maps a point of the box to -1..1 as seen by the user: x to the right,
+    /// y up and z towards the user.
+    pub fn normalize_point(&self, point: Vector3) -> Vector3 {
+        let normalized_x = point.x / (self.width / 2.0);
+        // the device's y axis points away from the device in every mode
+        let normalized_distance = (point.y - Y_OFFSET) / (self.height / 2.0) - 1.0;
+        let normalized_z = point.z / (self.depth / 2.0);
+        match self.tracking_mode {
+            TrackingMode::Hmd => Vector3::new(normalized_x, -normalized_z, -normalized_distance),
+            TrackingMode::ScreenTop => {
+                Vector3::new(-normalized_x, -normalized_distance, -normalized_z)
+            }
+            TrackingMode::Desktop | TrackingMode::Unknown => {
+                Vector3::new(normalized_x, normalized_distance, normalized_z)
+            }
+        }
     }
 }
 
@@ -126,17 +216,43 @@ impl Default for InteractionBox {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrackingEvent {
+    /// Tracking frame id, increasing with every tracking frame of a device.
     pub event_id: i64,
+    /// Id of the frame in the frame header, shared with the images of the frame.
+    pub frame_id: i64,
+    /// Time the frame was captured in microseconds, on the clock of `LeapGetNow`.
+    pub timestamp_us: i64,
+    /// Tracking frames per second at the time of the frame.
+    pub framerate: f32,
+    /// Id of the device the frame was tracked by, 0 if unknown.
+    pub device_id: u32,
     pub hands: Vec<Hand>,
     pub interaction_box: InteractionBox,
 }
 
 impl TrackingEvent {
-    pub fn from_raw(raw_tracking_event: &_LEAP_TRACKING_EVENT) -> TrackingEvent {
+    /// Converts a tracking event obtained through the `raw` API, like one of
+    /// `LeapInterpolateFrame`.
+    ///
+    /// # Safety
+    ///
+    /// `pHands` must point to `nHands` initialized hands.
+    #[cfg(feature = "raw")]
+    pub unsafe fn from_leapc(raw_tracking_event: &_LEAP_TRACKING_EVENT) -> TrackingEvent {
+        TrackingEvent::from_raw(raw_tracking_event)
+    }
+
+    pub(crate) fn from_raw(raw_tracking_event: &_LEAP_TRACKING_EVENT) -> TrackingEvent {
         unsafe {
             let mut tracking_event = TrackingEvent {
                 event_id: raw_tracking_event.tracking_frame_id,
+                frame_id: raw_tracking_event.info.frame_id,
+                timestamp_us: raw_tracking_event.info.timestamp,
+                framerate: raw_tracking_event.framerate,
+                device_id: 0,
                 hands: vec![],
                 interaction_box: InteractionBox::new(),
             };
@@ -148,4 +264,13 @@ impl TrackingEvent {
             tracking_event
         }
     }
+
+    /// Time since the frame was captured, zero while LeapC is not loaded.
+    pub fn latency(&self) -> Duration {
+        if !leapc::is_loaded() {
+            return Duration::ZERO;
+        }
+        let now_us = unsafe { LeapGetNow() };
+        Duration::from_micros((now_us - self.timestamp_us).max(0) as u64)
+    }
 }
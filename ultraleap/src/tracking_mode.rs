@@ -0,0 +1,40 @@
+use crate::{
+    _eLeapTrackingMode_eLeapTrackingMode_Desktop, _eLeapTrackingMode_eLeapTrackingMode_HMD,
+    _eLeapTrackingMode_eLeapTrackingMode_ScreenTop, _eLeapTrackingMode_eLeapTrackingMode_Unknown,
+    eLeapTrackingMode,
+};
+
+/// How the tracking device is mounted, the service optimizes tracking for it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackingMode {
+    /// Lying on a desk, facing up.
+    Desktop,
+    /// Mounted on a head mounted display, facing away from the user.
+    Hmd,
+    /// Mounted above a screen, facing down towards the user.
+    ScreenTop,
+    /// Not reported by the service yet.
+    #[default]
+    Unknown,
+}
+
+impl TrackingMode {
+    pub(crate) fn from_raw(raw_mode: eLeapTrackingMode) -> TrackingMode {
+        match raw_mode {
+            _eLeapTrackingMode_eLeapTrackingMode_Desktop => TrackingMode::Desktop,
+            _eLeapTrackingMode_eLeapTrackingMode_HMD => TrackingMode::Hmd,
+            _eLeapTrackingMode_eLeapTrackingMode_ScreenTop => TrackingMode::ScreenTop,
+            _ => TrackingMode::Unknown,
+        }
+    }
+
+    pub(crate) fn to_raw(self) -> eLeapTrackingMode {
+        match self {
+            TrackingMode::Desktop => _eLeapTrackingMode_eLeapTrackingMode_Desktop,
+            TrackingMode::Hmd => _eLeapTrackingMode_eLeapTrackingMode_HMD,
+            TrackingMode::ScreenTop => _eLeapTrackingMode_eLeapTrackingMode_ScreenTop,
+            TrackingMode::Unknown => _eLeapTrackingMode_eLeapTrackingMode_Unknown,
+        }
+    }
+}
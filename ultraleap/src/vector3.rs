@@ -0,0 +1,143 @@
+use crate::_LEAP_VECTOR;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Position or direction, positions are in millimeters from the device.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vector3 {
+    pub const ZERO: Vector3 = Vector3::new(0.0, 0.0, 0.0);
+    pub const X: Vector3 = Vector3::new(1.0, 0.0, 0.0);
+    pub const Y: Vector3 = Vector3::new(0.0, 1.0, 0.0);
+    pub const Z: Vector3 = Vector3::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3 { x, y, z }
+    }
+
+    pub(crate) fn from_raw(raw_vector: &_LEAP_VECTOR) -> Vector3 {
+        unsafe { Vector3::from_array(raw_vector.__bindgen_anon_1.v) }
+    }
+
+    pub const fn from_array(array: [f32; 3]) -> Vector3 {
+        Vector3::new(array[0], array[1], array[2])
+    }
+
+    pub const fn to_array(self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn dot(self, other: Vector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn distance(self, other: Vector3) -> f32 {
+        (other - self).length()
+    }
+
+    /// Vector of length 1 in the same direction, the zero vector stays zero.
+    pub fn normalize(self) -> Vector3 {
+        let length = self.length();
+        if length > 0.0 {
+            self / length
+        } else {
+            self
+        }
+    }
+
+    /// Linear interpolation, `t` of 0 gives `self` and 1 gives `other`.
+    pub fn lerp(self, other: Vector3, t: f32) -> Vector3 {
+        self + (other - self) * t
+    }
+}
+
+impl From<[f32; 3]> for Vector3 {
+    fn from(array: [f32; 3]) -> Vector3 {
+        Vector3::from_array(array)
+    }
+}
+
+impl From<Vector3> for [f32; 3] {
+    fn from(vector: Vector3) -> [f32; 3] {
+        vector.to_array()
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, other: Vector3) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, other: Vector3) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f32> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, factor: f32) -> Vector3 {
+        Vector3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl MulAssign<f32> for Vector3 {
+    fn mul_assign(&mut self, factor: f32) {
+        *self = *self * factor;
+    }
+}
+
+impl Div<f32> for Vector3 {
+    type Output = Vector3;
+
+    fn div(self, divisor: f32) -> Vector3 {
+        Vector3::new(self.x / divisor, self.y / divisor, self.z / divisor)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
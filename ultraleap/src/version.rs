@@ -0,0 +1,26 @@
+use crate::_LEAP_VERSION;
+use std::fmt;
+
+/// Version of the LeapC library or the tracking service.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Version {
+    pub major: i32,
+    pub minor: i32,
+    pub patch: i32,
+}
+
+impl Version {
+    pub(crate) fn from_raw(raw_version: &_LEAP_VERSION) -> Version {
+        Version {
+            major: raw_version.major,
+            minor: raw_version.minor,
+            patch: raw_version.patch,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}